@@ -0,0 +1,325 @@
+//! Macros for the [`effective`](https://docs.rs/effective) crate.
+//!
+//! You should not depend on this crate directly, instead enable the `macros` feature
+//! of `effective` and use the re-exports found there.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote,
+    punctuated::Punctuated,
+    visit_mut::VisitMut,
+    Block, Expr, Ident, Item, Label, Macro, Pat, Stmt, Token,
+};
+
+/// Write the body of an [`Effective`](https://docs.rs/effective/latest/effective/trait.Effective.html)
+/// using `yield`, `.await` and `?`.
+///
+/// See the documentation in the `effective` crate for more details.
+#[proc_macro]
+pub fn effective(input: TokenStream) -> TokenStream {
+    let Body { stmts } = parse_macro_input!(input as Body);
+    expand(stmts).into()
+}
+
+/// Loop over the items of an [`Effective`](https://docs.rs/effective/latest/effective/trait.Effective.html)
+/// using `for try await` syntax.
+///
+/// See the documentation in the `effective` crate for more details.
+#[proc_macro]
+pub fn for_effect(input: TokenStream) -> TokenStream {
+    let for_effect = parse_macro_input!(input as ForEffect);
+    for_effect.expand().into()
+}
+
+struct Body {
+    stmts: Vec<Stmt>,
+}
+
+impl Parse for Body {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            stmts: Block::parse_within(input)?,
+        })
+    }
+}
+
+fn expand(mut stmts: Vec<Stmt>) -> proc_macro2::TokenStream {
+    let yielder = Ident::new("__effective_yielder", Span::mixed_site());
+
+    let mut effects = Effects {
+        yielder: &yielder,
+        produces: false,
+        asynchrony: false,
+        fallible: false,
+        errors: None,
+    };
+    for stmt in &mut stmts {
+        effects.visit_stmt_mut(stmt);
+    }
+    if let Some(errors) = effects.errors {
+        return errors.into_compile_error();
+    }
+
+    let asynchrony = if effects.asynchrony {
+        quote!(::effective::Async)
+    } else {
+        quote!(::effective::Blocking)
+    };
+    let failure = if effects.fallible {
+        quote!(::effective::Failure<_>)
+    } else {
+        quote!(::core::convert::Infallible)
+    };
+
+    let body = quote! {
+        async move {
+            #[allow(unreachable_code)]
+            ::core::result::Result::Ok({ #(#stmts)* })
+        }
+    };
+
+    if effects.produces {
+        quote! {
+            ::effective::__private::multiple::<_, _, _, #asynchrony, #failure>(
+                move |#yielder| #body
+            )
+        }
+    } else {
+        quote! {
+            ::effective::__private::single::<_, _, #asynchrony, #failure>(#body)
+        }
+    }
+}
+
+/// Finds the effects used in the body, rewriting `yield` and `return` as it goes.
+///
+/// Closures, async blocks, nested `effective!` invocations and items have their own control flow,
+/// so they are not searched.
+struct Effects<'a> {
+    yielder: &'a Ident,
+    produces: bool,
+    asynchrony: bool,
+    fallible: bool,
+    errors: Option<syn::Error>,
+}
+
+impl Effects<'_> {
+    fn error(&mut self, error: syn::Error) {
+        match &mut self.errors {
+            Some(errors) => errors.combine(error),
+            None => self.errors = Some(error),
+        }
+    }
+
+    /// Search the tokens of a macro that could not be parsed.
+    ///
+    /// Nothing can be rewritten, so assume any `.await` or `?` found is used by the body.
+    /// A `?` only counts when it follows something that could end an expression,
+    /// so bounds like `?Sized` are not mistaken for it.
+    fn scan_tokens(&mut self, tokens: TokenStream2) {
+        let mut after_expr = false;
+        for token in tokens {
+            after_expr = match token {
+                TokenTree::Group(group) => {
+                    self.scan_tokens(group.stream());
+                    true
+                }
+                TokenTree::Ident(ident) if ident == "await" => {
+                    self.asynchrony = true;
+                    true
+                }
+                TokenTree::Ident(ident) if ident == "yield" => {
+                    self.error(syn::Error::new(
+                        ident.span(),
+                        "`yield` is not supported inside this macro invocation",
+                    ));
+                    false
+                }
+                TokenTree::Ident(_) | TokenTree::Literal(_) => true,
+                TokenTree::Punct(punct) if punct.as_char() == '?' => {
+                    self.fallible |= after_expr;
+                    after_expr
+                }
+                TokenTree::Punct(_) => false,
+            };
+        }
+    }
+}
+
+impl VisitMut for Effects<'_> {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::Closure(_) | Expr::Async(_) => {}
+            Expr::Await(_) => {
+                self.asynchrony = true;
+                syn::visit_mut::visit_expr_mut(self, expr);
+            }
+            Expr::Try(_) => {
+                self.fallible = true;
+                syn::visit_mut::visit_expr_mut(self, expr);
+            }
+            Expr::Yield(y) => {
+                self.produces = true;
+                let mut value = match y.expr.take() {
+                    Some(value) => *value,
+                    None => parse_quote!(()),
+                };
+                self.visit_expr_mut(&mut value);
+                let yielder = self.yielder;
+                *expr = parse_quote!(#yielder.yield_(#value).await);
+            }
+            Expr::Return(r) => {
+                let mut value = match r.expr.take() {
+                    Some(value) => *value,
+                    None => parse_quote!(()),
+                };
+                self.visit_expr_mut(&mut value);
+                *expr = parse_quote!(return ::core::result::Result::Ok(#value));
+            }
+            _ => syn::visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+
+    fn visit_macro_mut(&mut self, mac: &mut Macro) {
+        // a nested `effective!` has its own effects, like an async block
+        if mac
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident == "effective")
+        {
+            return;
+        }
+
+        if mac
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident == "for_effect")
+        {
+            if let Ok(mut for_effect) = mac.parse_body::<ForEffect>() {
+                self.asynchrony |= for_effect.asynchrony;
+                self.fallible |= for_effect.fallible;
+                self.visit_expr_mut(&mut for_effect.expr);
+                self.visit_block_mut(&mut for_effect.body);
+                mac.tokens = for_effect.into_token_stream();
+                return;
+            }
+        }
+
+        // most macros take expressions, which can be searched and rewritten like the rest of the body
+        match mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            Ok(mut args) => {
+                for arg in &mut args {
+                    self.visit_expr_mut(arg);
+                }
+                mac.tokens = args.into_token_stream();
+            }
+            Err(_) => self.scan_tokens(mac.tokens.clone()),
+        }
+    }
+
+    fn visit_item_mut(&mut self, _: &mut Item) {}
+}
+
+struct ForEffect {
+    label: Option<Label>,
+    fallible: bool,
+    asynchrony: bool,
+    pat: Pat,
+    expr: Expr,
+    body: Block,
+}
+
+impl Parse for ForEffect {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let label = if input.peek(syn::Lifetime) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        input.parse::<Token![for]>()?;
+        let fallible = input.parse::<Option<Token![try]>>()?.is_some();
+        let asynchrony = input.parse::<Option<Token![await]>>()?.is_some();
+        let pat = Pat::parse_multi_with_leading_vert(input)?;
+        input.parse::<Token![in]>()?;
+        let expr = Expr::parse_without_eager_brace(input)?;
+        let body = input.parse()?;
+        Ok(Self {
+            label,
+            fallible,
+            asynchrony,
+            pat,
+            expr,
+            body,
+        })
+    }
+}
+
+impl ToTokens for ForEffect {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let Self {
+            label,
+            fallible,
+            asynchrony,
+            pat,
+            expr,
+            body,
+        } = self;
+        let fallible = fallible.then(|| quote!(try));
+        let asynchrony = asynchrony.then(|| quote!(await));
+        tokens.extend(quote!(#label for #fallible #asynchrony #pat in #expr #body));
+    }
+}
+
+impl ForEffect {
+    fn expand(self) -> proc_macro2::TokenStream {
+        let Self {
+            label,
+            fallible,
+            asynchrony,
+            pat,
+            expr,
+            body,
+        } = self;
+
+        let effective = Ident::new("__effective_iter", Span::mixed_site());
+        let x = Ident::new("__effective_x", Span::mixed_site());
+
+        let next = if asynchrony {
+            quote!(::effective::__private::next(#effective.as_mut()).await)
+        } else {
+            quote!(::effective::__private::next_blocking(#effective.as_mut()))
+        };
+        let failure = if fallible {
+            quote!(match ::core::result::Result::<::core::convert::Infallible, _>::Err(
+                ::effective::Fallible::inner(#x)
+            )? {})
+        } else {
+            quote!(match #x {})
+        };
+        let pending = if asynchrony {
+            quote!(::effective::EffectResult::Pending(_) => ::core::unreachable!())
+        } else {
+            quote!(::effective::EffectResult::Pending(#x) => match #x {})
+        };
+
+        quote! {
+            {
+                let mut #effective = ::core::pin::pin!(#expr);
+                #label loop {
+                    let #pat = match #next {
+                        ::effective::EffectResult::Item(#x) => #x,
+                        ::effective::EffectResult::Failure(#x) => #failure,
+                        ::effective::EffectResult::Done(::effective::Multiple) => break,
+                        #pending,
+                    };
+                    #body
+                }
+            }
+        }
+    }
+}
@@ -1,27 +1,57 @@
 use std::{
     convert::Infallible,
     future::Future,
+    iter::FusedIterator,
     pin::Pin,
     task::{Context, Poll},
 };
 
-use futures_core::Stream;
+use futures_core::{future::FusedFuture, stream::FusedStream, Stream};
 use futures_util::task::noop_waker_ref;
 
-use crate::{Async, Blocking, EffectResult, Effective, Fallible, Multiple, ResultType, Single};
+use crate::{
+    Async, Blocking, EffectResult, Effective, Failure, Fallible, FusedEffective, Multiple,
+    ResultType, Single,
+};
 
 pin_project_lite::pin_project!(
     /// `Shim` implements some of the well known third-party traits from [`Effective`].
     /// It can be constructed using [`EffectiveExt::shim`](crate::EffectiveExt::shim).
+    ///
+    /// If the inner effective is fallible, the stream or iterator ends after the first failure,
+    /// so it must be a [`FusedEffective`] to be polled again safely.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers, Shim};
+    ///
+    /// let v: Vec<i32> = Shim { inner: wrappers::iterator([1, 2]) }.collect();
+    /// assert_eq!(v, [1, 2]);
+    ///
+    /// let e = wrappers::try_iterator(["1", "two", "3"].map(str::parse::<i32>));
+    /// let v: Vec<Result<i32, _>> = Shim { inner: e.fuse() }.collect();
+    /// assert_eq!(v.len(), 2);
+    /// assert!(v[1].is_err());
+    /// ```
     pub struct Shim<T> {
         #[pin]
         pub inner: T,
     }
 );
 
+/// The stream or iterator of a fallible effective keeps polling after a failure,
+/// so the effective must be fused to end there.
+pub trait EndsAfterFailure<E> {}
+
+impl<E> EndsAfterFailure<E> for Infallible {}
+
+impl<E: FusedEffective, F> EndsAfterFailure<E> for Failure<F> {}
+
 impl<E> Iterator for Shim<E>
 where
     E: Effective<Produces = Multiple, Async = Blocking> + Unpin,
+    E::Failure: EndsAfterFailure<E>,
 {
     type Item = ResultType<E>;
 
@@ -33,6 +63,17 @@ where
             EffectResult::Pending(x) => match x {},
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<E> FusedIterator for Shim<E>
+where
+    E: FusedEffective<Produces = Multiple, Async = Blocking> + Unpin,
+    E::Failure: EndsAfterFailure<E>,
+{
 }
 
 impl<E> Future for Shim<E>
@@ -51,20 +92,44 @@ where
     }
 }
 
+impl<E> FusedFuture for Shim<E>
+where
+    E: FusedEffective<Produces = Single, Async = Async>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
 impl<E> Stream for Shim<E>
 where
-    E: Effective<Produces = Multiple, Async = Async, Failure = Infallible>,
+    E: Effective<Produces = Multiple, Async = Async>,
+    E::Failure: EndsAfterFailure<E>,
 {
     type Item = ResultType<E>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         match self.project().inner.poll_effect(cx) {
             EffectResult::Item(x) => Poll::Ready(Some(success::<E>(x))),
-            EffectResult::Failure(x) => match x {},
+            EffectResult::Failure(x) => Poll::Ready(Some(failure::<E>(x))),
             EffectResult::Done(Multiple) => Poll::Ready(None),
             EffectResult::Pending(Async) => Poll::Pending,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<E> FusedStream for Shim<E>
+where
+    E: FusedEffective<Produces = Multiple, Async = Async>,
+    E::Failure: EndsAfterFailure<E>,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
 }
 
 fn success<E: Effective>(x: E::Item) -> ResultType<E> {
@@ -0,0 +1,100 @@
+use std::{ops::DerefMut, pin::Pin, task::Context};
+
+use crate::{Effective, EffectiveResult, FusedEffective};
+
+/// An owned, type-erased [`Effective`] that can be sent between threads
+pub type BoxEffective<'a, Item, Failure, Produces, Async> = Pin<
+    Box<
+        dyn Effective<Item = Item, Failure = Failure, Produces = Produces, Async = Async>
+            + Send
+            + 'a,
+    >,
+>;
+
+/// An owned, type-erased [`Effective`]
+pub type LocalBoxEffective<'a, Item, Failure, Produces, Async> = Pin<
+    Box<dyn Effective<Item = Item, Failure = Failure, Produces = Produces, Async = Async> + 'a>,
+>;
+
+impl<E> Effective for &mut E
+where
+    E: Effective + Unpin + ?Sized,
+{
+    type Item = E::Item;
+    type Failure = E::Failure;
+    type Produces = E::Produces;
+    type Async = E::Async;
+
+    fn poll_effect(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> EffectiveResult<Self> {
+        E::poll_effect(Pin::new(&mut **self), cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
+
+impl<E> Effective for Box<E>
+where
+    E: Effective + Unpin + ?Sized,
+{
+    type Item = E::Item;
+    type Failure = E::Failure;
+    type Produces = E::Produces;
+    type Async = E::Async;
+
+    fn poll_effect(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> EffectiveResult<Self> {
+        E::poll_effect(Pin::new(&mut **self), cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
+
+impl<P> Effective for Pin<P>
+where
+    P: DerefMut,
+    P::Target: Effective,
+{
+    type Item = <P::Target as Effective>::Item;
+    type Failure = <P::Target as Effective>::Failure;
+    type Produces = <P::Target as Effective>::Produces;
+    type Async = <P::Target as Effective>::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> EffectiveResult<Self> {
+        self.as_deref_mut().poll_effect(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
+
+impl<E> FusedEffective for &mut E
+where
+    E: FusedEffective + Unpin + ?Sized,
+{
+    fn is_terminated(&self) -> bool {
+        (**self).is_terminated()
+    }
+}
+
+impl<E> FusedEffective for Box<E>
+where
+    E: FusedEffective + Unpin + ?Sized,
+{
+    fn is_terminated(&self) -> bool {
+        (**self).is_terminated()
+    }
+}
+
+impl<P> FusedEffective for Pin<P>
+where
+    P: DerefMut,
+    P::Target: FusedEffective,
+{
+    fn is_terminated(&self) -> bool {
+        (**self).is_terminated()
+    }
+}
@@ -0,0 +1,46 @@
+//! Runtime support for the [`for_effect!`](crate::for_effect) macro
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_util::task::noop_waker_ref;
+
+use crate::{Async, Blocking, EffectResult, Effective, EffectiveResult, Multiple};
+
+/// Poll the next effect, waiting out any [`Pending`](EffectResult::Pending)
+pub fn next<E>(inner: Pin<&mut E>) -> Next<'_, E>
+where
+    E: Effective<Produces = Multiple, Async = Async>,
+{
+    Next { inner }
+}
+
+/// Poll the next effect of a blocking effective
+pub fn next_blocking<E>(inner: Pin<&mut E>) -> EffectiveResult<E>
+where
+    E: Effective<Produces = Multiple, Async = Blocking>,
+{
+    inner.poll_effect(&mut Context::from_waker(noop_waker_ref()))
+}
+
+/// Produced by the [`next()`] function
+pub struct Next<'a, E> {
+    inner: Pin<&'a mut E>,
+}
+
+impl<E> Future for Next<'_, E>
+where
+    E: Effective<Produces = Multiple, Async = Async>,
+{
+    type Output = EffectiveResult<E>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.inner.as_mut().poll_effect(cx) {
+            EffectResult::Pending(Async) => Poll::Pending,
+            x => Poll::Ready(x),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Runtime support for the [`effective!`](crate::effective) macro
+
+use std::{
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll},
+};
+
+use crate::{Asynchrony, EffectResult, Effective, Fallible, Multiple, Single};
+
+/// Create an effective that produces the output of the future as its single item
+pub fn single<Fut, R, A, F>(fut: Fut) -> GenSingle<Fut, A, F>
+where
+    Fut: Future<Output = Result<R, F::Failure>>,
+    A: Asynchrony,
+    F: Fallible,
+{
+    GenSingle {
+        fut,
+        _marker: PhantomData,
+    }
+}
+
+/// Create an effective that produces every value passed to the [`Yielder`]
+pub fn multiple<G, Fut, T, A, F>(body: G) -> GenMultiple<Fut, T, A, F>
+where
+    G: FnOnce(Yielder<T>) -> Fut,
+    Fut: Future<Output = Result<(), F::Failure>>,
+    A: Asynchrony,
+    F: Fallible,
+{
+    let slot = Arc::new(Mutex::new(None));
+    let fut = body(Yielder { slot: slot.clone() });
+    GenMultiple {
+        fut,
+        slot,
+        _marker: PhantomData,
+    }
+}
+
+/// Passes values out of the generator body
+///
+/// The body holds a `&Yielder` across every `yield`, so the yielder must be `Sync` for the
+/// generator to be `Send`. A `Cell` would make every generator `!Send`, and sharing one safely
+/// needs `unsafe`, so the slot is a `Mutex` instead. It is only ever locked by the task that
+/// polls the generator, so the lock is never contended and costs a pair of atomic operations.
+pub struct Yielder<T> {
+    slot: Arc<Mutex<Option<T>>>,
+}
+
+impl<T> Yielder<T> {
+    pub fn yield_(&self, value: T) -> Yield<'_, T> {
+        Yield {
+            yielder: self,
+            value: Some(value),
+        }
+    }
+}
+
+/// Produced by the [`yield_()`](Yielder::yield_) method
+pub struct Yield<'a, T> {
+    yielder: &'a Yielder<T>,
+    value: Option<T>,
+}
+
+impl<T> Unpin for Yield<'_, T> {}
+
+impl<T> Future for Yield<'_, T> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+        match self.value.take() {
+            // suspend the body once so the generator can hand out the value
+            Some(value) => {
+                *lock(&self.yielder.slot) = Some(value);
+                Poll::Pending
+            }
+            None => Poll::Ready(()),
+        }
+    }
+}
+
+fn lock<T>(slot: &Mutex<Option<T>>) -> std::sync::MutexGuard<'_, Option<T>> {
+    slot.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`effective!`](crate::effective) macro when the body does not `yield`
+    pub struct GenSingle<Fut, A, F> {
+        #[pin]
+        fut: Fut,
+        _marker: PhantomData<fn() -> (A, F)>,
+    }
+);
+
+impl<Fut, R, A, F> Effective for GenSingle<Fut, A, F>
+where
+    Fut: Future<Output = Result<R, F::Failure>>,
+    A: Asynchrony,
+    F: Fallible,
+{
+    type Item = R;
+    type Failure = F;
+    type Produces = Single;
+    type Async = A;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        match self.project().fut.poll(cx) {
+            Poll::Ready(Ok(x)) => EffectResult::Item(x),
+            Poll::Ready(Err(x)) => EffectResult::Failure(F::from(x)),
+            // the body only awaits if `A` is `Async`
+            Poll::Pending => EffectResult::Pending(A::new()),
+        }
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`effective!`](crate::effective) macro when the body does `yield`
+    pub struct GenMultiple<Fut, T, A, F> {
+        #[pin]
+        fut: Fut,
+        slot: Arc<Mutex<Option<T>>>,
+        _marker: PhantomData<fn() -> (A, F)>,
+    }
+);
+
+impl<Fut, T, A, F> Effective for GenMultiple<Fut, T, A, F>
+where
+    Fut: Future<Output = Result<(), F::Failure>>,
+    A: Asynchrony,
+    F: Fallible,
+{
+    type Item = T;
+    type Failure = F;
+    type Produces = Multiple;
+    type Async = A;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let this = self.project();
+        match this.fut.poll(cx) {
+            Poll::Ready(Ok(())) => EffectResult::Done(Multiple),
+            Poll::Ready(Err(x)) => EffectResult::Failure(F::from(x)),
+            Poll::Pending => match lock(this.slot).take() {
+                Some(x) => EffectResult::Item(x),
+                // the body only awaits if `A` is `Async`
+                None => EffectResult::Pending(A::new()),
+            },
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! Effect adaptors to subtract the 'iterable' effect by reducing the items into a single value
+
+use std::{convert::Infallible, pin::Pin, task::Context};
+
+use crate::{Async, Asynchrony, EffectResult, Effective, Fallible, Multiple, Single};
+
+use super::collect::CollectIterator;
+
+/// Reduces items into a single value, for use with [`Aggregate`]
+pub trait Accumulator<T> {
+    type Output;
+
+    /// Add an item into the accumulator
+    fn push(&mut self, item: T);
+
+    /// Take the final value out of the accumulator
+    fn finish(&mut self) -> Self::Output;
+
+    /// Add all of the items and take the final value.
+    /// Used when the items are available without any other effects
+    fn aggregate(&mut self, iter: impl Iterator<Item = T>) -> Self::Output {
+        for item in iter {
+            self.push(item);
+        }
+        self.finish()
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`count()`](super::EffectiveExt::count), [`sum()`](super::EffectiveExt::sum),
+    /// [`product()`](super::EffectiveExt::product), [`min()`](super::EffectiveExt::min),
+    /// [`max()`](super::EffectiveExt::max), [`min_by_key()`](super::EffectiveExt::min_by_key),
+    /// [`max_by_key()`](super::EffectiveExt::max_by_key) and [`last()`](super::EffectiveExt::last) methods
+    pub struct Aggregate<E, A> {
+        #[pin]
+        pub(super) inner: E,
+        pub(super) acc: A,
+    }
+);
+
+impl<E, A> Effective for Aggregate<E, A>
+where
+    E: Effective<Produces = Multiple>,
+    A: Accumulator<E::Item>,
+{
+    type Item = A::Output;
+    type Failure = E::Failure;
+    type Produces = Single;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+
+        // specialisation
+        if !<Self::Async as Asynchrony>::IS_ASYNC && !<Self::Failure as Fallible>::FALLIBLE {
+            return EffectResult::Item(this.acc.aggregate(CollectIterator { inner: this.inner }));
+        }
+
+        loop {
+            match this.inner.as_mut().poll_effect(cx) {
+                EffectResult::Item(x) => this.acc.push(x),
+                EffectResult::Failure(x) => return EffectResult::Failure(x),
+                EffectResult::Done(Multiple) => return EffectResult::Item(this.acc.finish()),
+                EffectResult::Pending(x) => return EffectResult::Pending(x),
+            }
+        }
+    }
+}
+
+impl<E, A> std::future::Future for Aggregate<E, A>
+where
+    E: Effective<Produces = Multiple, Async = Async, Failure = Infallible>,
+    A: Accumulator<E::Item>,
+{
+    type Output = A::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> std::task::Poll<Self::Output> {
+        match self.poll_effect(cx) {
+            EffectResult::Item(value) => std::task::Poll::Ready(value),
+            EffectResult::Failure(x) => match x {},
+            EffectResult::Done(x) => match x {},
+            EffectResult::Pending(_) => std::task::Poll::Pending,
+        }
+    }
+}
+
+pub type Count<E> = Aggregate<E, CountAcc>;
+pub type Sum<E, S> = Aggregate<E, SumAcc<S>>;
+pub type Product<E, S> = Aggregate<E, ProductAcc<S>>;
+pub type Min<E> = Aggregate<E, MinAcc<<E as Effective>::Item>>;
+pub type Max<E> = Aggregate<E, MaxAcc<<E as Effective>::Item>>;
+pub type MinByKey<E, K, F> = Aggregate<E, MinByKeyAcc<<E as Effective>::Item, K, F>>;
+pub type MaxByKey<E, K, F> = Aggregate<E, MaxByKeyAcc<<E as Effective>::Item, K, F>>;
+pub type Last<E> = Aggregate<E, LastAcc<<E as Effective>::Item>>;
+
+/// Counts the items
+#[derive(Default)]
+pub struct CountAcc(pub(super) usize);
+
+impl<T> Accumulator<T> for CountAcc {
+    type Output = usize;
+
+    fn push(&mut self, _: T) {
+        self.0 += 1;
+    }
+    fn finish(&mut self) -> usize {
+        self.0
+    }
+    fn aggregate(&mut self, iter: impl Iterator<Item = T>) -> usize {
+        self.0 + iter.count()
+    }
+}
+
+/// Adds the items together
+pub struct SumAcc<S>(pub(super) Option<S>);
+
+impl<T, S> Accumulator<T> for SumAcc<S>
+where
+    S: std::iter::Sum<T> + std::iter::Sum<S>,
+{
+    type Output = S;
+
+    fn push(&mut self, item: T) {
+        let item = S::sum(std::iter::once(item));
+        self.0 = Some(S::sum(self.0.take().into_iter().chain(Some(item))));
+    }
+    fn finish(&mut self) -> S {
+        S::sum(self.0.take().into_iter())
+    }
+    fn aggregate(&mut self, iter: impl Iterator<Item = T>) -> S {
+        let sum = S::sum(iter);
+        S::sum(self.0.take().into_iter().chain(Some(sum)))
+    }
+}
+
+/// Multiplies the items together
+pub struct ProductAcc<S>(pub(super) Option<S>);
+
+impl<T, S> Accumulator<T> for ProductAcc<S>
+where
+    S: std::iter::Product<T> + std::iter::Product<S>,
+{
+    type Output = S;
+
+    fn push(&mut self, item: T) {
+        let item = S::product(std::iter::once(item));
+        self.0 = Some(S::product(self.0.take().into_iter().chain(Some(item))));
+    }
+    fn finish(&mut self) -> S {
+        S::product(self.0.take().into_iter())
+    }
+    fn aggregate(&mut self, iter: impl Iterator<Item = T>) -> S {
+        let product = S::product(iter);
+        S::product(self.0.take().into_iter().chain(Some(product)))
+    }
+}
+
+/// Finds the first minimum item
+pub struct MinAcc<T>(pub(super) Option<T>);
+
+impl<T: Ord> Accumulator<T> for MinAcc<T> {
+    type Output = Option<T>;
+
+    fn push(&mut self, item: T) {
+        match &self.0 {
+            Some(min) if *min <= item => {}
+            _ => self.0 = Some(item),
+        }
+    }
+    fn finish(&mut self) -> Option<T> {
+        self.0.take()
+    }
+}
+
+/// Finds the last maximum item
+pub struct MaxAcc<T>(pub(super) Option<T>);
+
+impl<T: Ord> Accumulator<T> for MaxAcc<T> {
+    type Output = Option<T>;
+
+    fn push(&mut self, item: T) {
+        match &self.0 {
+            Some(max) if *max > item => {}
+            _ => self.0 = Some(item),
+        }
+    }
+    fn finish(&mut self) -> Option<T> {
+        self.0.take()
+    }
+}
+
+/// Finds the first item with the minimum key
+pub struct MinByKeyAcc<T, K, F> {
+    pub(super) min: Option<(K, T)>,
+    pub(super) key: F,
+}
+
+impl<T, K, F> Accumulator<T> for MinByKeyAcc<T, K, F>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    type Output = Option<T>;
+
+    fn push(&mut self, item: T) {
+        let key = (self.key)(&item);
+        match &self.min {
+            Some((min, _)) if *min <= key => {}
+            _ => self.min = Some((key, item)),
+        }
+    }
+    fn finish(&mut self) -> Option<T> {
+        self.min.take().map(|(_, item)| item)
+    }
+}
+
+/// Finds the last item with the maximum key
+pub struct MaxByKeyAcc<T, K, F> {
+    pub(super) max: Option<(K, T)>,
+    pub(super) key: F,
+}
+
+impl<T, K, F> Accumulator<T> for MaxByKeyAcc<T, K, F>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    type Output = Option<T>;
+
+    fn push(&mut self, item: T) {
+        let key = (self.key)(&item);
+        match &self.max {
+            Some((max, _)) if *max > key => {}
+            _ => self.max = Some((key, item)),
+        }
+    }
+    fn finish(&mut self) -> Option<T> {
+        self.max.take().map(|(_, item)| item)
+    }
+}
+
+/// Keeps the last item
+pub struct LastAcc<T>(pub(super) Option<T>);
+
+impl<T> Accumulator<T> for LastAcc<T> {
+    type Output = Option<T>;
+
+    fn push(&mut self, item: T) {
+        self.0 = Some(item);
+    }
+    fn finish(&mut self) -> Option<T> {
+        self.0.take()
+    }
+    fn aggregate(&mut self, iter: impl Iterator<Item = T>) -> Option<T> {
+        iter.last().or_else(|| self.0.take())
+    }
+}
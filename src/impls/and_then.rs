@@ -0,0 +1,116 @@
+//! Effect adaptors that run another effective after a single effective completes
+
+use std::{pin::Pin, task::Context};
+
+use crate::{
+    utils::{from_async, from_fail, AsyncPair, AsyncWith, FalliblePair, FallibleWith},
+    EffectResult, Effective, Failure, Single,
+};
+
+pin_project_lite::pin_project!(
+    #[project = StateProj]
+    pub(super) enum State<E, F, C> {
+        First {
+            #[pin]
+            inner: E,
+            func: Option<F>,
+        },
+        Second {
+            #[pin]
+            eff: C,
+        },
+    }
+);
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`and_then()`](super::EffectiveExt::and_then) method
+    pub struct AndThen<E, F, C> {
+        #[pin]
+        pub(super) state: State<E, F, C>,
+    }
+);
+
+impl<E, F, C> Effective for AndThen<E, F, C>
+where
+    E: Effective<Produces = Single>,
+    F: FnOnce(E::Item) -> C,
+    C: Effective<Produces = Single>,
+    E::Async: AsyncWith<C::Async>,
+    E::Failure: FallibleWith<C::Failure>,
+{
+    type Item = C::Item;
+    type Failure = FalliblePair<E, C>;
+    type Produces = Single;
+    type Async = AsyncPair<E, C>;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::First { inner, func } => match inner.poll_effect(cx) {
+                    EffectResult::Item(x) => {
+                        let eff = (func.take().expect("polled after completion"))(x);
+                        this.state.set(State::Second { eff });
+                    }
+                    EffectResult::Failure(x) => return EffectResult::Failure(x.into_fail()),
+                    EffectResult::Done(x) => match x {},
+                    EffectResult::Pending(x) => return EffectResult::Pending(x.into_async()),
+                },
+                StateProj::Second { eff } => match eff.poll_effect(cx) {
+                    EffectResult::Item(x) => return EffectResult::Item(x),
+                    EffectResult::Failure(x) => return EffectResult::Failure(from_fail::<E, C>(x)),
+                    EffectResult::Done(x) => match x {},
+                    EffectResult::Pending(x) => {
+                        return EffectResult::Pending(from_async::<E, C>(x))
+                    }
+                },
+            }
+        }
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`or_else()`](super::EffectiveExt::or_else) method
+    pub struct OrElse<E, F, C> {
+        #[pin]
+        pub(super) state: State<E, F, C>,
+    }
+);
+
+impl<T, E, F, C> Effective for OrElse<E, F, C>
+where
+    E: Effective<Produces = Single, Failure = Failure<T>>,
+    F: FnOnce(T) -> C,
+    C: Effective<Item = E::Item, Produces = Single>,
+    E::Async: AsyncWith<C::Async>,
+{
+    type Item = E::Item;
+    type Failure = C::Failure;
+    type Produces = Single;
+    type Async = AsyncPair<E, C>;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::First { inner, func } => match inner.poll_effect(cx) {
+                    EffectResult::Item(x) => return EffectResult::Item(x),
+                    EffectResult::Failure(Failure(x)) => {
+                        let eff = (func.take().expect("polled after completion"))(x);
+                        this.state.set(State::Second { eff });
+                    }
+                    EffectResult::Done(x) => match x {},
+                    EffectResult::Pending(x) => return EffectResult::Pending(x.into_async()),
+                },
+                StateProj::Second { eff } => match eff.poll_effect(cx) {
+                    EffectResult::Item(x) => return EffectResult::Item(x),
+                    EffectResult::Failure(x) => return EffectResult::Failure(x),
+                    EffectResult::Done(x) => match x {},
+                    EffectResult::Pending(x) => {
+                        return EffectResult::Pending(from_async::<E, C>(x))
+                    }
+                },
+            }
+        }
+    }
+}
@@ -3,8 +3,13 @@
 use std::{
     future::poll_fn,
     future::Future,
-    pin::Pin,
-    task::{Context, Poll},
+    pin::{pin, Pin},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
 };
 
 use crate::{Async, Blocking, EffectResult, Effective};
@@ -13,6 +18,12 @@ pub trait Executor {
     fn block_on<R>(&mut self, f: impl Future<Output = R>) -> R;
 }
 
+impl<E: Executor + ?Sized> Executor for &mut E {
+    fn block_on<R>(&mut self, f: impl Future<Output = R>) -> R {
+        E::block_on(self, f)
+    }
+}
+
 pin_project_lite::pin_project!(
     /// Produced by the [`block()`](super::EffectiveExt::block) method
     pub struct Block<E, R> {
@@ -48,6 +59,60 @@ where
     }
 }
 
+/// A dependency-free [`Executor`] that runs the future on the current thread,
+/// parking the thread while the future is pending.
+///
+/// # Example
+///
+/// ```
+/// use effective::{impls::{blocking::ParkExecutor, EffectiveExt}, wrappers};
+///
+/// let e = wrappers::future(async { 1 });
+///
+/// let v: i32 = e.block_on(ParkExecutor).get();
+/// assert_eq!(v, 1);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ParkExecutor;
+
+impl Executor for ParkExecutor {
+    fn block_on<R>(&mut self, f: impl Future<Output = R>) -> R {
+        let mut f = pin!(f);
+        let unparker = Arc::new(Unparker {
+            thread: thread::current(),
+            notified: AtomicBool::new(false),
+        });
+        let waker = Waker::from(unparker.clone());
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(x) = f.as_mut().poll(&mut cx) {
+                return x;
+            }
+            // `park` can wake up spuriously, so wait until we were actually notified
+            while !unparker.notified.swap(false, Ordering::Acquire) {
+                thread::park();
+            }
+        }
+    }
+}
+
+struct Unparker {
+    thread: Thread,
+    notified: AtomicBool,
+}
+
+impl Wake for Unparker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        if !self.notified.swap(true, Ordering::Release) {
+            self.thread.unpark();
+        }
+    }
+}
+
 #[cfg(feature = "futures-executor")]
 #[cfg_attr(docsrs, doc(cfg(feature = "futures-executor")))]
 impl Executor for futures_executor::LocalPool {
@@ -63,3 +128,85 @@ impl Executor for tokio::runtime::Runtime {
         tokio::runtime::Runtime::block_on(self, f)
     }
 }
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl Executor for &tokio::runtime::Runtime {
+    fn block_on<R>(&mut self, f: impl Future<Output = R>) -> R {
+        tokio::runtime::Runtime::block_on(self, f)
+    }
+}
+
+/// Blocks using [`Handle::block_on`](tokio::runtime::Handle::block_on).
+///
+/// With the `tokio-multi-thread` feature, if called from within a multi-threaded runtime,
+/// this uses `block_in_place` to avoid stalling the other tasks.
+///
+/// # Panics
+///
+/// Like [`Handle::block_on`](tokio::runtime::Handle::block_on), this panics if called
+/// from within a current thread runtime, or from within any runtime without the
+/// `tokio-multi-thread` feature.
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl Executor for tokio::runtime::Handle {
+    fn block_on<R>(&mut self, f: impl Future<Output = R>) -> R {
+        #[cfg(feature = "tokio-multi-thread")]
+        {
+            use tokio::runtime::{Handle, RuntimeFlavor};
+
+            if let Ok(current) = Handle::try_current() {
+                if current.runtime_flavor() == RuntimeFlavor::MultiThread {
+                    return tokio::task::block_in_place(|| Handle::block_on(self, f));
+                }
+            }
+        }
+        tokio::runtime::Handle::block_on(self, f)
+    }
+}
+
+/// Runs the future, and any tasks spawned onto the local set, within the runtime
+/// using [`LocalSet::block_on`](tokio::task::LocalSet::block_on).
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use effective::{impls::EffectiveExt, wrappers};
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread()
+///     .enable_time()
+///     .build()
+///     .unwrap();
+/// let local = tokio::task::LocalSet::new();
+///
+/// let e = wrappers::future(async {
+///     let task = tokio::task::spawn_local(async {
+///         tokio::time::sleep(Duration::from_millis(1)).await;
+///         1
+///     });
+///     task.await.unwrap() + 1
+/// });
+///
+/// let v: i32 = e.block_on((&runtime, &local)).get();
+/// assert_eq!(v, 2);
+/// ```
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl Executor for (&tokio::runtime::Runtime, &tokio::task::LocalSet) {
+    fn block_on<R>(&mut self, f: impl Future<Output = R>) -> R {
+        let (runtime, local) = *self;
+        local.block_on(runtime, f)
+    }
+}
+
+/// Runs the future, and any tasks spawned onto the local set, within the runtime
+/// using [`LocalSet::block_on`](tokio::task::LocalSet::block_on).
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl Executor for (tokio::runtime::Runtime, tokio::task::LocalSet) {
+    fn block_on<R>(&mut self, f: impl Future<Output = R>) -> R {
+        let (runtime, local) = &*self;
+        local.block_on(runtime, f)
+    }
+}
@@ -0,0 +1,176 @@
+//! Effect adaptors that run many effects concurrently
+
+use std::{collections::VecDeque, pin::Pin, task::Context};
+
+use crate::{
+    utils::{from_async, from_fail, AsyncPair, AsyncWith, FalliblePair, FallibleWith},
+    EffectResult, Effective, Multiple, Single,
+};
+
+type Output<E> = <<E as Effective>::Item as Effective>::Item;
+
+pub(super) enum Slot<E: Effective> {
+    Waiting(Pin<Box<E>>),
+    Ready(E::Item),
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`buffered()`](super::EffectiveExt::buffered) method
+    pub struct Buffered<E>
+    where
+        E: Effective,
+        E::Item: Effective,
+    {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) in_flight: VecDeque<Slot<E::Item>>,
+        pub(super) limit: usize,
+    }
+);
+
+impl<E> Effective for Buffered<E>
+where
+    E: Effective<Produces = Multiple>,
+    E::Item: Effective<Produces = Single>,
+    E::Async: AsyncWith<<E::Item as Effective>::Async>,
+    E::Failure: FallibleWith<<E::Item as Effective>::Failure>,
+{
+    type Item = Output<E>;
+    type Failure = FalliblePair<E, E::Item>;
+    type Produces = Multiple;
+    type Async = AsyncPair<E, E::Item>;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        let mut pending = None;
+
+        while this.in_flight.len() < *this.limit {
+            let Some(inner) = this.inner.as_mut().as_pin_mut() else {
+                break;
+            };
+            match inner.poll_effect(cx) {
+                EffectResult::Item(x) => this.in_flight.push_back(Slot::Waiting(Box::pin(x))),
+                EffectResult::Failure(x) => {
+                    this.in_flight.clear();
+                    return EffectResult::Failure(x.into_fail());
+                }
+                EffectResult::Done(Multiple) => this.inner.set(None),
+                EffectResult::Pending(x) => {
+                    pending = Some(x.into_async());
+                    break;
+                }
+            }
+        }
+
+        for slot in this.in_flight.iter_mut() {
+            let Slot::Waiting(e) = slot else { continue };
+            match e.as_mut().poll_effect(cx) {
+                EffectResult::Item(x) => *slot = Slot::Ready(x),
+                EffectResult::Failure(x) => {
+                    this.in_flight.clear();
+                    this.inner.set(None);
+                    return EffectResult::Failure(from_fail::<E, E::Item>(x));
+                }
+                EffectResult::Done(x) => match x {},
+                EffectResult::Pending(x) => pending = Some(from_async::<E, E::Item>(x)),
+            }
+        }
+
+        match this.in_flight.front() {
+            Some(Slot::Ready(_)) => match this.in_flight.pop_front() {
+                Some(Slot::Ready(x)) => EffectResult::Item(x),
+                _ => unreachable!(),
+            },
+            None if this.inner.is_none() => EffectResult::Done(Multiple),
+            _ => EffectResult::Pending(pending.expect("an effect was pending")),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        buffered_size_hint(self.inner.as_ref(), self.in_flight.len())
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`buffer_unordered()`](super::EffectiveExt::buffer_unordered) method
+    pub struct BufferUnordered<E>
+    where
+        E: Effective,
+        E::Item: Effective,
+    {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) in_flight: Vec<Pin<Box<E::Item>>>,
+        pub(super) limit: usize,
+    }
+);
+
+impl<E> Effective for BufferUnordered<E>
+where
+    E: Effective<Produces = Multiple>,
+    E::Item: Effective<Produces = Single>,
+    E::Async: AsyncWith<<E::Item as Effective>::Async>,
+    E::Failure: FallibleWith<<E::Item as Effective>::Failure>,
+{
+    type Item = Output<E>;
+    type Failure = FalliblePair<E, E::Item>;
+    type Produces = Multiple;
+    type Async = AsyncPair<E, E::Item>;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        let mut pending = None;
+
+        while this.in_flight.len() < *this.limit {
+            let Some(inner) = this.inner.as_mut().as_pin_mut() else {
+                break;
+            };
+            match inner.poll_effect(cx) {
+                EffectResult::Item(x) => this.in_flight.push(Box::pin(x)),
+                EffectResult::Failure(x) => {
+                    this.in_flight.clear();
+                    return EffectResult::Failure(x.into_fail());
+                }
+                EffectResult::Done(Multiple) => this.inner.set(None),
+                EffectResult::Pending(x) => {
+                    pending = Some(x.into_async());
+                    break;
+                }
+            }
+        }
+
+        for i in 0..this.in_flight.len() {
+            match this.in_flight[i].as_mut().poll_effect(cx) {
+                EffectResult::Item(x) => {
+                    drop(this.in_flight.swap_remove(i));
+                    return EffectResult::Item(x);
+                }
+                EffectResult::Failure(x) => {
+                    this.in_flight.clear();
+                    this.inner.set(None);
+                    return EffectResult::Failure(from_fail::<E, E::Item>(x));
+                }
+                EffectResult::Done(x) => match x {},
+                EffectResult::Pending(x) => pending = Some(from_async::<E, E::Item>(x)),
+            }
+        }
+
+        if this.in_flight.is_empty() && this.inner.is_none() {
+            EffectResult::Done(Multiple)
+        } else {
+            EffectResult::Pending(pending.expect("an effect was pending"))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        buffered_size_hint(self.inner.as_ref(), self.in_flight.len())
+    }
+}
+
+fn buffered_size_hint<E: Effective>(inner: Option<&E>, in_flight: usize) -> (usize, Option<usize>) {
+    let (lower, upper) = inner.map_or((0, Some(0)), E::size_hint);
+    (
+        lower.saturating_add(in_flight),
+        upper.and_then(|x| x.checked_add(in_flight)),
+    )
+}
@@ -0,0 +1,74 @@
+//! Effect adaptors that join effectives end to end
+
+use std::{pin::Pin, task::Context};
+
+use crate::{
+    utils::{from_async, from_fail, AsyncPair, AsyncWith, FalliblePair, FallibleWith},
+    EffectResult, Effective, Iterable, Multiple,
+};
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`chain()`](super::EffectiveExt::chain) method
+    pub struct Chain<E1, E2> {
+        #[pin]
+        pub(super) first: Option<E1>,
+        #[pin]
+        pub(super) second: Option<E2>,
+    }
+);
+
+impl<E1, E2> Effective for Chain<E1, E2>
+where
+    E1: Effective,
+    E2: Effective<Item = E1::Item>,
+    E1::Async: AsyncWith<E2::Async>,
+    E1::Failure: FallibleWith<E2::Failure>,
+{
+    type Item = E1::Item;
+    type Failure = FalliblePair<E1, E2>;
+    type Produces = Multiple;
+    type Async = AsyncPair<E1, E2>;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        if let Some(first) = this.first.as_mut().as_pin_mut() {
+            match first.poll_effect(cx) {
+                EffectResult::Item(x) => {
+                    if !<E1::Produces as Iterable>::MULTIPLE {
+                        this.first.set(None);
+                    }
+                    return EffectResult::Item(x);
+                }
+                EffectResult::Failure(x) => return EffectResult::Failure(x.into_fail()),
+                EffectResult::Done(_) => this.first.set(None),
+                EffectResult::Pending(x) => return EffectResult::Pending(x.into_async()),
+            }
+        }
+
+        if let Some(second) = this.second.as_mut().as_pin_mut() {
+            match second.poll_effect(cx) {
+                EffectResult::Item(x) => {
+                    if !<E2::Produces as Iterable>::MULTIPLE {
+                        this.second.set(None);
+                    }
+                    return EffectResult::Item(x);
+                }
+                EffectResult::Failure(x) => return EffectResult::Failure(from_fail::<E1, E2>(x)),
+                EffectResult::Done(_) => this.second.set(None),
+                EffectResult::Pending(x) => return EffectResult::Pending(from_async::<E1, E2>(x)),
+            }
+        }
+
+        EffectResult::Done(Multiple)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower1, upper1) = self.first.as_ref().map_or((0, Some(0)), E1::size_hint);
+        let (lower2, upper2) = self.second.as_ref().map_or((0, Some(0)), E2::size_hint);
+        let upper = match (upper1, upper2) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (lower1.saturating_add(lower2), upper)
+    }
+}
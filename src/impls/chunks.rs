@@ -0,0 +1,297 @@
+//! Effect adaptors that group the items into batches
+
+use std::{future::Future, pin::Pin, task::Context, time::Duration};
+
+use crate::{timer::Timer, Async, EffectResult, Effective, Multiple};
+
+/// What to do with a partially filled batch when the upstream fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnFailure {
+    /// Produce the partial batch, then the failure
+    #[default]
+    Flush,
+    /// Drop the partial batch and only produce the failure
+    Discard,
+}
+
+pub(super) struct Batch<T, F> {
+    items: Vec<T>,
+    size: usize,
+    failure: Option<F>,
+    on_failure: OnFailure,
+}
+
+impl<T, F> Batch<T, F> {
+    pub(super) fn new(size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Self {
+            items: Vec::new(),
+            size,
+            failure: None,
+            on_failure: OnFailure::default(),
+        }
+    }
+
+    /// Add an item, returning the batch if it is now full
+    fn push(&mut self, item: T) -> Option<Vec<T>> {
+        self.items.push(item);
+        (self.items.len() >= self.size).then(|| self.take())
+    }
+
+    fn take(&mut self) -> Vec<T> {
+        std::mem::take(&mut self.items)
+    }
+
+    /// The upstream failed. Returns the partial batch if it should be produced before the failure
+    fn fail(&mut self, failure: F) -> Result<Vec<T>, F> {
+        match self.on_failure {
+            OnFailure::Flush if !self.items.is_empty() => {
+                self.failure = Some(failure);
+                Ok(self.take())
+            }
+            _ => {
+                self.items.clear();
+                Err(failure)
+            }
+        }
+    }
+
+    fn size_hint(&self, (lower, upper): (usize, Option<usize>)) -> (usize, Option<usize>) {
+        let len = self.items.len();
+        (
+            lower.saturating_add(len).div_ceil(self.size),
+            upper
+                .and_then(|x| x.checked_add(len))
+                .map(|x| x.div_ceil(self.size)),
+        )
+    }
+}
+
+fn flush<T, F, P, A>(failure: Result<Vec<T>, F>) -> EffectResult<Vec<T>, F, P, A> {
+    match failure {
+        Ok(items) => EffectResult::Item(items),
+        Err(x) => EffectResult::Failure(x),
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`chunks()`](super::EffectiveExt::chunks) method
+    pub struct Chunks<E>
+    where
+        E: Effective,
+    {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) batch: Batch<E::Item, E::Failure>,
+    }
+);
+
+impl<E: Effective> Chunks<E> {
+    /// Configure what happens to a partial batch when the upstream fails.
+    /// Defaults to [`OnFailure::Flush`]
+    pub fn on_failure(mut self, on_failure: OnFailure) -> Self {
+        self.batch.on_failure = on_failure;
+        self
+    }
+}
+
+impl<E> Effective for Chunks<E>
+where
+    E: Effective<Produces = Multiple>,
+{
+    type Item = Vec<E::Item>;
+    type Failure = E::Failure;
+    type Produces = Multiple;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        if let Some(x) = this.batch.failure.take() {
+            return EffectResult::Failure(x);
+        }
+        while let Some(inner) = this.inner.as_mut().as_pin_mut() {
+            match inner.poll_effect(cx) {
+                EffectResult::Item(x) => {
+                    if let Some(items) = this.batch.push(x) {
+                        return EffectResult::Item(items);
+                    }
+                }
+                EffectResult::Failure(x) => return flush(this.batch.fail(x)),
+                EffectResult::Done(Multiple) => this.inner.set(None),
+                EffectResult::Pending(x) => return EffectResult::Pending(x),
+            }
+        }
+        if this.batch.items.is_empty() {
+            EffectResult::Done(Multiple)
+        } else {
+            EffectResult::Item(this.batch.take())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Some(inner) => self.batch.size_hint(inner.size_hint()),
+            None => self.batch.size_hint((0, Some(0))),
+        }
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`ready_chunks()`](super::EffectiveExt::ready_chunks) method
+    pub struct ReadyChunks<E>
+    where
+        E: Effective,
+    {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) batch: Batch<E::Item, E::Failure>,
+    }
+);
+
+impl<E: Effective> ReadyChunks<E> {
+    /// Configure what happens to a partial batch when the upstream fails.
+    /// Defaults to [`OnFailure::Flush`]
+    pub fn on_failure(mut self, on_failure: OnFailure) -> Self {
+        self.batch.on_failure = on_failure;
+        self
+    }
+}
+
+impl<E> Effective for ReadyChunks<E>
+where
+    E: Effective<Produces = Multiple, Async = Async>,
+{
+    type Item = Vec<E::Item>;
+    type Failure = E::Failure;
+    type Produces = Multiple;
+    type Async = Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        if let Some(x) = this.batch.failure.take() {
+            return EffectResult::Failure(x);
+        }
+        while let Some(inner) = this.inner.as_mut().as_pin_mut() {
+            match inner.poll_effect(cx) {
+                EffectResult::Item(x) => {
+                    if let Some(items) = this.batch.push(x) {
+                        return EffectResult::Item(items);
+                    }
+                }
+                EffectResult::Failure(x) => return flush(this.batch.fail(x)),
+                EffectResult::Done(Multiple) => this.inner.set(None),
+                EffectResult::Pending(Async) if this.batch.items.is_empty() => {
+                    return EffectResult::Pending(Async)
+                }
+                EffectResult::Pending(Async) => return EffectResult::Item(this.batch.take()),
+            }
+        }
+        if this.batch.items.is_empty() {
+            EffectResult::Done(Multiple)
+        } else {
+            EffectResult::Item(this.batch.take())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.batch.items.len();
+        let (lower, upper) = match &self.inner {
+            Some(inner) => inner.size_hint(),
+            None => (0, Some(0)),
+        };
+        (
+            usize::from(lower.saturating_add(len) > 0),
+            upper.and_then(|x| x.checked_add(len)),
+        )
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`chunks_timeout()`](super::EffectiveExt::chunks_timeout) method
+    pub struct ChunksTimeout<E, T>
+    where
+        E: Effective,
+        T: Timer,
+    {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) batch: Batch<E::Item, E::Failure>,
+        pub(super) timer: T,
+        pub(super) duration: Duration,
+        #[pin]
+        pub(super) sleep: Option<T::Sleep>,
+    }
+);
+
+impl<E: Effective, T: Timer> ChunksTimeout<E, T> {
+    /// Configure what happens to a partial batch when the upstream fails.
+    /// Defaults to [`OnFailure::Flush`]
+    pub fn on_failure(mut self, on_failure: OnFailure) -> Self {
+        self.batch.on_failure = on_failure;
+        self
+    }
+}
+
+impl<E, T> Effective for ChunksTimeout<E, T>
+where
+    E: Effective<Produces = Multiple, Async = Async>,
+    T: Timer,
+{
+    type Item = Vec<E::Item>;
+    type Failure = E::Failure;
+    type Produces = Multiple;
+    type Async = Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        if let Some(x) = this.batch.failure.take() {
+            return EffectResult::Failure(x);
+        }
+        while let Some(inner) = this.inner.as_mut().as_pin_mut() {
+            match inner.poll_effect(cx) {
+                EffectResult::Item(x) => {
+                    // the timeout starts with the first item of the batch
+                    if this.batch.items.is_empty() {
+                        this.sleep.set(Some(this.timer.sleep(*this.duration)));
+                    }
+                    if let Some(items) = this.batch.push(x) {
+                        this.sleep.set(None);
+                        return EffectResult::Item(items);
+                    }
+                }
+                EffectResult::Failure(x) => {
+                    this.sleep.set(None);
+                    return flush(this.batch.fail(x));
+                }
+                EffectResult::Done(Multiple) => this.inner.set(None),
+                EffectResult::Pending(Async) => {
+                    if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                        if sleep.poll(cx).is_ready() {
+                            this.sleep.set(None);
+                            return EffectResult::Item(this.batch.take());
+                        }
+                    }
+                    return EffectResult::Pending(Async);
+                }
+            }
+        }
+        this.sleep.set(None);
+        if this.batch.items.is_empty() {
+            EffectResult::Done(Multiple)
+        } else {
+            EffectResult::Item(this.batch.take())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.batch.items.len();
+        let (lower, upper) = match &self.inner {
+            Some(inner) => inner.size_hint(),
+            None => (0, Some(0)),
+        };
+        (
+            lower.saturating_add(len).div_ceil(self.batch.size),
+            upper.and_then(|x| x.checked_add(len)),
+        )
+    }
+}
@@ -47,8 +47,8 @@ where
     }
 }
 
-struct CollectIterator<'a, E> {
-    inner: Pin<&'a mut E>,
+pub(super) struct CollectIterator<'a, E> {
+    pub(super) inner: Pin<&'a mut E>,
 }
 
 // specialisation
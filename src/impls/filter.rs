@@ -0,0 +1,192 @@
+//! Effect adaptors that drop some of the items
+
+use std::{pin::Pin, task::Context};
+
+use crate::{
+    utils::{from_async, from_fail, AsyncPair, AsyncWith, FalliblePair, FallibleWith},
+    EffectResult, Effective, Iterable, Multiple, Single,
+};
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`filter()`](super::EffectiveExt::filter) method
+    pub struct Filter<E, P> {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) predicate: P,
+    }
+);
+
+impl<E, P> Effective for Filter<E, P>
+where
+    E: Effective,
+    P: FnMut(&E::Item) -> bool,
+{
+    type Item = E::Item;
+    type Failure = E::Failure;
+    type Produces = Multiple;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        while let Some(inner) = this.inner.as_mut().as_pin_mut() {
+            match inner.poll_effect(cx) {
+                EffectResult::Item(x) => {
+                    if !<E::Produces as Iterable>::MULTIPLE {
+                        this.inner.set(None);
+                    }
+                    if (this.predicate)(&x) {
+                        return EffectResult::Item(x);
+                    }
+                }
+                EffectResult::Failure(x) => return EffectResult::Failure(x),
+                EffectResult::Done(_) => this.inner.set(None),
+                EffectResult::Pending(x) => return EffectResult::Pending(x),
+            }
+        }
+        EffectResult::Done(Multiple)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Some(inner) => (0, inner.size_hint().1),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`filter_map()`](super::EffectiveExt::filter_map) method
+    pub struct FilterMap<E, F> {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) func: F,
+    }
+);
+
+impl<R, E, F> Effective for FilterMap<E, F>
+where
+    E: Effective,
+    F: FnMut(E::Item) -> Option<R>,
+{
+    type Item = R;
+    type Failure = E::Failure;
+    type Produces = Multiple;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        while let Some(inner) = this.inner.as_mut().as_pin_mut() {
+            match inner.poll_effect(cx) {
+                EffectResult::Item(x) => {
+                    if !<E::Produces as Iterable>::MULTIPLE {
+                        this.inner.set(None);
+                    }
+                    if let Some(x) = (this.func)(x) {
+                        return EffectResult::Item(x);
+                    }
+                }
+                EffectResult::Failure(x) => return EffectResult::Failure(x),
+                EffectResult::Done(_) => this.inner.set(None),
+                EffectResult::Pending(x) => return EffectResult::Pending(x),
+            }
+        }
+        EffectResult::Done(Multiple)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Some(inner) => (0, inner.size_hint().1),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+pin_project_lite::pin_project!(
+    #[project = StateProj]
+    pub(super) enum State<T, C> {
+        Acc,
+        Eff {
+            #[pin]
+            eff: C,
+            item: Option<T>,
+        },
+    }
+);
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`filter_effect()`](super::EffectiveExt::filter_effect) method
+    pub struct FilterEffect<E, P, C>
+    where
+        E: Effective,
+    {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) predicate: P,
+        #[pin]
+        pub(super) state: State<E::Item, C>,
+    }
+);
+
+impl<E, P, C> Effective for FilterEffect<E, P, C>
+where
+    E: Effective,
+    P: FnMut(&E::Item) -> C,
+    C: Effective<Item = bool, Produces = Single>,
+    E::Async: AsyncWith<C::Async>,
+    E::Failure: FallibleWith<C::Failure>,
+{
+    type Item = E::Item;
+    type Failure = FalliblePair<E, C>;
+    type Produces = Multiple;
+    type Async = AsyncPair<E, C>;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::Acc => {
+                    let Some(inner) = this.inner.as_mut().as_pin_mut() else {
+                        return EffectResult::Done(Multiple);
+                    };
+                    match inner.poll_effect(cx) {
+                        EffectResult::Item(x) => {
+                            if !<E::Produces as Iterable>::MULTIPLE {
+                                this.inner.set(None);
+                            }
+                            let eff = (this.predicate)(&x);
+                            this.state.set(State::Eff { eff, item: Some(x) });
+                        }
+                        EffectResult::Failure(x) => return EffectResult::Failure(x.into_fail()),
+                        EffectResult::Done(_) => this.inner.set(None),
+                        EffectResult::Pending(x) => return EffectResult::Pending(x.into_async()),
+                    }
+                }
+                StateProj::Eff { eff, item } => match eff.poll_effect(cx) {
+                    EffectResult::Item(keep) => {
+                        let item = item.take().unwrap();
+                        this.state.set(State::Acc);
+                        if keep {
+                            return EffectResult::Item(item);
+                        }
+                    }
+                    EffectResult::Done(x) => match x {},
+                    EffectResult::Failure(x) => return EffectResult::Failure(from_fail::<E, C>(x)),
+                    EffectResult::Pending(x) => {
+                        return EffectResult::Pending(from_async::<E, C>(x))
+                    }
+                },
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = match self.state {
+            State::Acc => 0,
+            State::Eff { .. } => 1,
+        };
+        match &self.inner {
+            Some(inner) => (0, inner.size_hint().1.and_then(|x| x.checked_add(pending))),
+            None => (0, Some(pending)),
+        }
+    }
+}
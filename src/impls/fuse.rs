@@ -0,0 +1,63 @@
+//! Effect adaptors that make it safe to poll after completion
+
+use std::{pin::Pin, task::Context};
+
+use crate::{Asynchrony, EffectResult, Effective, FusedEffective, Iterable, SealedMarker};
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`fuse()`](super::EffectiveExt::fuse) method
+    pub struct Fuse<E> {
+        #[pin]
+        pub(super) inner: Option<E>,
+    }
+);
+
+impl<E: Effective> Effective for Fuse<E> {
+    type Item = E::Item;
+    type Failure = E::Failure;
+    type Produces = E::Produces;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        let Some(inner) = this.inner.as_mut().as_pin_mut() else {
+            if <E::Produces as Iterable>::MULTIPLE {
+                return EffectResult::Done(SealedMarker::new());
+            }
+            if <E::Async as Asynchrony>::IS_ASYNC {
+                return EffectResult::Pending(SealedMarker::new());
+            }
+            panic!("single blocking `Fuse` polled after completion")
+        };
+        match inner.poll_effect(cx) {
+            EffectResult::Item(x) => {
+                if !<E::Produces as Iterable>::MULTIPLE {
+                    this.inner.set(None);
+                }
+                EffectResult::Item(x)
+            }
+            EffectResult::Failure(x) => {
+                this.inner.set(None);
+                EffectResult::Failure(x)
+            }
+            EffectResult::Done(x) => {
+                this.inner.set(None);
+                EffectResult::Done(x)
+            }
+            EffectResult::Pending(x) => EffectResult::Pending(x),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Some(inner) => inner.size_hint(),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<E: Effective> FusedEffective for Fuse<E> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_none()
+    }
+}
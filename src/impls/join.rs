@@ -0,0 +1,135 @@
+//! Effect adaptors that wait on many single effectives at once
+
+use std::{pin::Pin, task::Context};
+
+use crate::{
+    utils::{AsyncWith, FallibleWith},
+    EffectResult, Effective, EffectiveExt, Single,
+};
+
+use super::Zip;
+
+/// Produced by the [`join()`] function
+pub type Join<E1, E2> = Zip<E1, E2>;
+
+/// Wait for both effectives to produce their item.
+///
+/// Both effectives are polled concurrently on the same task. If either fails,
+/// the other is dropped and the failure is returned.
+///
+/// # Example
+///
+/// ```
+/// use effective::{impls::EffectiveExt, wrappers};
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+///
+/// async fn lookup(x: i32) -> Option<i32> {
+///     x.checked_mul(2)
+/// }
+///
+/// let a = wrappers::future(lookup(1)).flatten_fallible();
+/// let b = wrappers::once(3);
+///
+/// let v: Option<(i32, i32)> = effective::join(a, b).block_on(runtime).try_get();
+/// assert_eq!(v, Some((2, 3)));
+/// ```
+pub fn join<E1, E2>(a: E1, b: E2) -> Join<E1, E2>
+where
+    E1: Effective<Produces = Single>,
+    E2: Effective<Produces = Single>,
+    E1::Async: AsyncWith<E2::Async>,
+    E1::Failure: FallibleWith<E2::Failure>,
+{
+    a.zip(b)
+}
+
+/// Wait for all of the effectives to produce their item.
+///
+/// All effectives are polled concurrently on the same task. If any fail,
+/// the rest are dropped and the failure is returned.
+///
+/// # Example
+///
+/// ```
+/// use effective::{impls::EffectiveExt, wrappers};
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+///
+/// async fn lookup(x: i32) -> Option<i32> {
+///     x.checked_mul(2)
+/// }
+///
+/// let e = (1..=3).map(|x| wrappers::future(lookup(x)).flatten_fallible());
+///
+/// let v: Option<Vec<i32>> = effective::join_all(e).block_on(runtime).try_get();
+/// assert_eq!(v, Some(vec![2, 4, 6]));
+/// ```
+pub fn join_all<I>(effectives: I) -> JoinAll<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Effective<Produces = Single>,
+{
+    JoinAll {
+        slots: effectives
+            .into_iter()
+            .map(|e| Slot::Waiting(Box::pin(e)))
+            .collect(),
+    }
+}
+
+enum Slot<E: Effective> {
+    Waiting(Pin<Box<E>>),
+    Ready(E::Item),
+    Taken,
+}
+
+/// Produced by the [`join_all()`] function
+pub struct JoinAll<E: Effective> {
+    slots: Box<[Slot<E>]>,
+}
+
+impl<E: Effective> Unpin for JoinAll<E> {}
+
+impl<E> Effective for JoinAll<E>
+where
+    E: Effective<Produces = Single>,
+{
+    type Item = Vec<E::Item>;
+    type Failure = E::Failure;
+    type Produces = Single;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let this = self.get_mut();
+        let mut pending = None;
+
+        for slot in this.slots.iter_mut() {
+            let Slot::Waiting(e) = slot else { continue };
+            match e.as_mut().poll_effect(cx) {
+                EffectResult::Item(x) => *slot = Slot::Ready(x),
+                EffectResult::Failure(x) => {
+                    // the rest are no longer needed
+                    this.slots = Box::new([]);
+                    return EffectResult::Failure(x);
+                }
+                EffectResult::Done(x) => match x {},
+                EffectResult::Pending(x) => pending = Some(x),
+            }
+        }
+
+        if let Some(x) = pending {
+            return EffectResult::Pending(x);
+        }
+
+        let items = this
+            .slots
+            .iter_mut()
+            .map(|slot| match std::mem::replace(slot, Slot::Taken) {
+                Slot::Ready(x) => x,
+                _ => unreachable!("polled after completion"),
+            })
+            .collect();
+        EffectResult::Item(items)
+    }
+}
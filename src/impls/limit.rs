@@ -0,0 +1,252 @@
+//! Effect adaptors that limit how many items are produced
+
+use std::{pin::Pin, task::Context};
+
+use crate::{EffectResult, Effective, Multiple};
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`take()`](super::EffectiveExt::take) method
+    pub struct Take<E> {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) remaining: usize,
+    }
+);
+
+impl<E> Effective for Take<E>
+where
+    E: Effective<Produces = Multiple>,
+{
+    type Item = E::Item;
+    type Failure = E::Failure;
+    type Produces = Multiple;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        if *this.remaining == 0 {
+            this.inner.set(None);
+        }
+        let Some(inner) = this.inner.as_mut().as_pin_mut() else {
+            return EffectResult::Done(Multiple);
+        };
+        match inner.poll_effect(cx) {
+            EffectResult::Item(x) => {
+                *this.remaining -= 1;
+                if *this.remaining == 0 {
+                    this.inner.set(None);
+                }
+                EffectResult::Item(x)
+            }
+            EffectResult::Failure(x) => EffectResult::Failure(x),
+            EffectResult::Done(Multiple) => {
+                this.inner.set(None);
+                EffectResult::Done(Multiple)
+            }
+            EffectResult::Pending(x) => EffectResult::Pending(x),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Some(inner) if self.remaining > 0 => {
+                let (lower, upper) = inner.size_hint();
+                let upper = match upper {
+                    Some(upper) => upper.min(self.remaining),
+                    None => self.remaining,
+                };
+                (lower.min(self.remaining), Some(upper))
+            }
+            _ => (0, Some(0)),
+        }
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`skip()`](super::EffectiveExt::skip) method
+    pub struct Skip<E> {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) remaining: usize,
+    }
+);
+
+impl<E> Effective for Skip<E>
+where
+    E: Effective<Produces = Multiple>,
+{
+    type Item = E::Item;
+    type Failure = E::Failure;
+    type Produces = Multiple;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        while let Some(inner) = this.inner.as_mut().as_pin_mut() {
+            match inner.poll_effect(cx) {
+                EffectResult::Item(_) if *this.remaining > 0 => *this.remaining -= 1,
+                EffectResult::Item(x) => return EffectResult::Item(x),
+                EffectResult::Failure(x) => return EffectResult::Failure(x),
+                EffectResult::Done(Multiple) => this.inner.set(None),
+                EffectResult::Pending(x) => return EffectResult::Pending(x),
+            }
+        }
+        EffectResult::Done(Multiple)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Some(inner) => {
+                let (lower, upper) = inner.size_hint();
+                (
+                    lower.saturating_sub(self.remaining),
+                    upper.map(|x| x.saturating_sub(self.remaining)),
+                )
+            }
+            None => (0, Some(0)),
+        }
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`take_while()`](super::EffectiveExt::take_while) method
+    pub struct TakeWhile<E, P> {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) predicate: P,
+    }
+);
+
+impl<E, P> Effective for TakeWhile<E, P>
+where
+    E: Effective<Produces = Multiple>,
+    P: FnMut(&E::Item) -> bool,
+{
+    type Item = E::Item;
+    type Failure = E::Failure;
+    type Produces = Multiple;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        let Some(inner) = this.inner.as_mut().as_pin_mut() else {
+            return EffectResult::Done(Multiple);
+        };
+        match inner.poll_effect(cx) {
+            EffectResult::Item(x) if (this.predicate)(&x) => EffectResult::Item(x),
+            EffectResult::Item(_) | EffectResult::Done(Multiple) => {
+                this.inner.set(None);
+                EffectResult::Done(Multiple)
+            }
+            EffectResult::Failure(x) => EffectResult::Failure(x),
+            EffectResult::Pending(x) => EffectResult::Pending(x),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Some(inner) => (0, inner.size_hint().1),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`skip_while()`](super::EffectiveExt::skip_while) method
+    pub struct SkipWhile<E, P> {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) predicate: P,
+        pub(super) skipping: bool,
+    }
+);
+
+impl<E, P> Effective for SkipWhile<E, P>
+where
+    E: Effective<Produces = Multiple>,
+    P: FnMut(&E::Item) -> bool,
+{
+    type Item = E::Item;
+    type Failure = E::Failure;
+    type Produces = Multiple;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        while let Some(inner) = this.inner.as_mut().as_pin_mut() {
+            match inner.poll_effect(cx) {
+                EffectResult::Item(x) => {
+                    if *this.skipping && (this.predicate)(&x) {
+                        continue;
+                    }
+                    *this.skipping = false;
+                    return EffectResult::Item(x);
+                }
+                EffectResult::Failure(x) => return EffectResult::Failure(x),
+                EffectResult::Done(Multiple) => this.inner.set(None),
+                EffectResult::Pending(x) => return EffectResult::Pending(x),
+            }
+        }
+        EffectResult::Done(Multiple)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Some(inner) if self.skipping => (0, inner.size_hint().1),
+            Some(inner) => inner.size_hint(),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`step_by()`](super::EffectiveExt::step_by) method
+    pub struct StepBy<E> {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) step: usize,
+        pub(super) skip: usize,
+    }
+);
+
+impl<E> Effective for StepBy<E>
+where
+    E: Effective<Produces = Multiple>,
+{
+    type Item = E::Item;
+    type Failure = E::Failure;
+    type Produces = Multiple;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        while let Some(inner) = this.inner.as_mut().as_pin_mut() {
+            match inner.poll_effect(cx) {
+                EffectResult::Item(_) if *this.skip > 0 => *this.skip -= 1,
+                EffectResult::Item(x) => {
+                    *this.skip = *this.step - 1;
+                    return EffectResult::Item(x);
+                }
+                EffectResult::Failure(x) => return EffectResult::Failure(x),
+                EffectResult::Done(Multiple) => this.inner.set(None),
+                EffectResult::Pending(x) => return EffectResult::Pending(x),
+            }
+        }
+        EffectResult::Done(Multiple)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the next item is produced after skipping `skip` items, then one every `step`
+        let count = |n: usize| match n.checked_sub(self.skip) {
+            Some(n) if n > 0 => 1 + (n - 1) / self.step,
+            _ => 0,
+        };
+        match &self.inner {
+            Some(inner) => {
+                let (lower, upper) = inner.size_hint();
+                (count(lower), upper.map(count))
+            }
+            None => (0, Some(0)),
+        }
+    }
+}
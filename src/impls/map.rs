@@ -2,7 +2,10 @@
 
 use std::{pin::Pin, task::Context};
 
-use crate::{EffectResult, Effective};
+use crate::{
+    utils::{from_async, from_fail, AsyncPair, AsyncWith, FalliblePair, FallibleWith},
+    EffectResult, Effective, Iterable, Multiple, Single,
+};
 
 pin_project_lite::pin_project!(
     /// Produced by the [`map()`](super::EffectiveExt::map) method
@@ -37,3 +40,189 @@ where
         self.inner.size_hint()
     }
 }
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`enumerate()`](super::EffectiveExt::enumerate) method
+    pub struct Enumerate<E> {
+        #[pin]
+        pub(super) inner: E,
+        pub(super) count: usize,
+    }
+);
+
+impl<E> Effective for Enumerate<E>
+where
+    E: Effective,
+{
+    type Item = (usize, E::Item);
+    type Failure = E::Failure;
+    type Produces = E::Produces;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let this = self.project();
+        match this.inner.poll_effect(cx) {
+            EffectResult::Item(x) => {
+                let i = *this.count;
+                *this.count += 1;
+                EffectResult::Item((i, x))
+            }
+            EffectResult::Failure(x) => EffectResult::Failure(x),
+            EffectResult::Done(x) => EffectResult::Done(x),
+            EffectResult::Pending(x) => EffectResult::Pending(x),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`inspect()`](super::EffectiveExt::inspect) method
+    pub struct Inspect<E, F> {
+        #[pin]
+        pub(super) inner: E,
+        pub(super) inspect: F,
+    }
+);
+
+impl<E, F> Effective for Inspect<E, F>
+where
+    E: Effective,
+    F: FnMut(&E::Item),
+{
+    type Item = E::Item;
+    type Failure = E::Failure;
+    type Produces = E::Produces;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let this = self.project();
+        match this.inner.poll_effect(cx) {
+            EffectResult::Item(x) => {
+                (this.inspect)(&x);
+                EffectResult::Item(x)
+            }
+            EffectResult::Failure(x) => EffectResult::Failure(x),
+            EffectResult::Done(x) => EffectResult::Done(x),
+            EffectResult::Pending(x) => EffectResult::Pending(x),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`scan()`](super::EffectiveExt::scan) method
+    pub struct Scan<E, S, F> {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) state: S,
+        pub(super) func: F,
+    }
+);
+
+impl<R, E, S, F> Effective for Scan<E, S, F>
+where
+    E: Effective<Produces = Multiple>,
+    F: FnMut(&mut S, E::Item) -> Option<R>,
+{
+    type Item = R;
+    type Failure = E::Failure;
+    type Produces = Multiple;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        let Some(inner) = this.inner.as_mut().as_pin_mut() else {
+            return EffectResult::Done(Multiple);
+        };
+        match inner.poll_effect(cx) {
+            EffectResult::Item(x) => match (this.func)(this.state, x) {
+                Some(x) => EffectResult::Item(x),
+                None => {
+                    this.inner.set(None);
+                    EffectResult::Done(Multiple)
+                }
+            },
+            EffectResult::Failure(x) => EffectResult::Failure(x),
+            EffectResult::Done(Multiple) => {
+                this.inner.set(None);
+                EffectResult::Done(Multiple)
+            }
+            EffectResult::Pending(x) => EffectResult::Pending(x),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Some(inner) => (0, inner.size_hint().1),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`then()`](super::EffectiveExt::then) method
+    pub struct Then<E, F, C> {
+        #[pin]
+        pub(super) inner: E,
+        pub(super) func: F,
+        #[pin]
+        pub(super) eff: Option<C>,
+    }
+);
+
+impl<E, F, C> Effective for Then<E, F, C>
+where
+    E: Effective,
+    F: FnMut(E::Item) -> C,
+    C: Effective<Produces = Single>,
+    E::Async: AsyncWith<C::Async>,
+    E::Failure: FallibleWith<C::Failure>,
+{
+    type Item = C::Item;
+    type Failure = FalliblePair<E, C>;
+    type Produces = E::Produces;
+    type Async = AsyncPair<E, C>;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        loop {
+            if let Some(eff) = this.eff.as_mut().as_pin_mut() {
+                match eff.poll_effect(cx) {
+                    EffectResult::Item(x) => {
+                        this.eff.set(None);
+                        return EffectResult::Item(x);
+                    }
+                    EffectResult::Done(x) => match x {},
+                    EffectResult::Failure(x) => return EffectResult::Failure(from_fail::<E, C>(x)),
+                    EffectResult::Pending(x) => {
+                        return EffectResult::Pending(from_async::<E, C>(x))
+                    }
+                }
+            }
+            match this.inner.as_mut().poll_effect(cx) {
+                EffectResult::Item(x) => this.eff.set(Some((this.func)(x))),
+                EffectResult::Failure(x) => return EffectResult::Failure(x.into_fail()),
+                EffectResult::Done(x) => return EffectResult::Done(x),
+                EffectResult::Pending(x) => return EffectResult::Pending(x.into_async()),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        if self.eff.is_some() && <E::Produces as Iterable>::MULTIPLE {
+            (
+                lower.saturating_add(1),
+                upper.and_then(|x| x.checked_add(1)),
+            )
+        } else {
+            (lower, upper)
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Effect adaptors that convert a failure of one type into a failure of another
+
+use std::{pin::Pin, task::Context};
+
+use crate::{EffectResult, Effective, Failure};
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`map_err()`](super::EffectiveExt::map_err) method
+    pub struct MapErr<E, F> {
+        #[pin]
+        pub(super) inner: E,
+        pub(super) map: F,
+    }
+);
+
+impl<T, R, E, F> Effective for MapErr<E, F>
+where
+    E: Effective<Failure = Failure<T>>,
+    F: FnMut(T) -> R,
+{
+    type Item = E::Item;
+    type Failure = Failure<R>;
+    type Produces = E::Produces;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let this = self.project();
+        match this.inner.poll_effect(cx) {
+            EffectResult::Item(x) => EffectResult::Item(x),
+            EffectResult::Failure(Failure(x)) => EffectResult::Failure(Failure((this.map)(x))),
+            EffectResult::Done(x) => EffectResult::Done(x),
+            EffectResult::Pending(x) => EffectResult::Pending(x),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
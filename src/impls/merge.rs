@@ -0,0 +1,194 @@
+//! Effect adaptors that interleave the items of many effectives
+
+use std::{pin::Pin, task::Context};
+
+use crate::{
+    utils::{from_async, from_fail, AsyncPair, AsyncWith, FalliblePair, FallibleWith},
+    EffectResult, Effective, Iterable, Multiple,
+};
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`merge()`](super::EffectiveExt::merge) method
+    pub struct Merge<E1, E2> {
+        #[pin]
+        pub(super) first: Option<E1>,
+        #[pin]
+        pub(super) second: Option<E2>,
+        pub(super) second_turn: bool,
+    }
+);
+
+impl<E1, E2> Effective for Merge<E1, E2>
+where
+    E1: Effective,
+    E2: Effective<Item = E1::Item>,
+    E1::Async: AsyncWith<E2::Async>,
+    E1::Failure: FallibleWith<E2::Failure>,
+{
+    type Item = E1::Item;
+    type Failure = FalliblePair<E1, E2>;
+    type Produces = Multiple;
+    type Async = AsyncPair<E1, E2>;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        let mut pending = None;
+
+        // take turns polling first so neither side can starve the other
+        for poll_second in [*this.second_turn, !*this.second_turn] {
+            if poll_second {
+                let Some(second) = this.second.as_mut().as_pin_mut() else {
+                    continue;
+                };
+                match second.poll_effect(cx) {
+                    EffectResult::Item(x) => {
+                        if !<E2::Produces as Iterable>::MULTIPLE {
+                            this.second.set(None);
+                        }
+                        *this.second_turn = false;
+                        return EffectResult::Item(x);
+                    }
+                    EffectResult::Failure(x) => {
+                        this.first.set(None);
+                        this.second.set(None);
+                        return EffectResult::Failure(from_fail::<E1, E2>(x));
+                    }
+                    EffectResult::Done(_) => this.second.set(None),
+                    EffectResult::Pending(x) => pending = Some(from_async::<E1, E2>(x)),
+                }
+            } else {
+                let Some(first) = this.first.as_mut().as_pin_mut() else {
+                    continue;
+                };
+                match first.poll_effect(cx) {
+                    EffectResult::Item(x) => {
+                        if !<E1::Produces as Iterable>::MULTIPLE {
+                            this.first.set(None);
+                        }
+                        *this.second_turn = true;
+                        return EffectResult::Item(x);
+                    }
+                    EffectResult::Failure(x) => {
+                        this.first.set(None);
+                        this.second.set(None);
+                        return EffectResult::Failure(x.into_fail());
+                    }
+                    EffectResult::Done(_) => this.first.set(None),
+                    EffectResult::Pending(x) => pending = Some(x.into_async()),
+                }
+            }
+        }
+
+        match pending {
+            Some(x) => EffectResult::Pending(x),
+            None => EffectResult::Done(Multiple),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower1, upper1) = self.first.as_ref().map_or((0, Some(0)), E1::size_hint);
+        let (lower2, upper2) = self.second.as_ref().map_or((0, Some(0)), E2::size_hint);
+        let upper = match (upper1, upper2) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (lower1.saturating_add(lower2), upper)
+    }
+}
+
+/// Interleave the items of all the effectives, producing whichever is ready first.
+///
+/// The effectives are polled in round-robin order, so none of them can starve the others.
+/// The first failure from any of them is returned immediately.
+///
+/// # Example
+///
+/// ```
+/// use effective::{impls::EffectiveExt, wrappers};
+///
+/// let e = effective::merge_all([
+///     wrappers::iterator(vec![1, 2, 3]),
+///     wrappers::iterator(vec![4, 5]),
+///     wrappers::iterator(vec![6]),
+/// ]);
+///
+/// let v: Vec<i32> = e.collect().get();
+/// assert_eq!(v, [1, 4, 6, 2, 5, 3]);
+/// ```
+pub fn merge_all<I>(effectives: I) -> MergeAll<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Effective,
+{
+    MergeAll {
+        inner: effectives.into_iter().map(Box::pin).collect(),
+        next: 0,
+    }
+}
+
+/// Produced by the [`merge_all()`] function
+pub struct MergeAll<E> {
+    inner: Vec<Pin<Box<E>>>,
+    next: usize,
+}
+
+impl<E> Unpin for MergeAll<E> {}
+
+impl<E: Effective> Effective for MergeAll<E> {
+    type Item = E::Item;
+    type Failure = E::Failure;
+    type Produces = Multiple;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let this = self.get_mut();
+        let mut pending = None;
+
+        let mut polled = 0;
+        while polled < this.inner.len() {
+            let i = (this.next + polled) % this.inner.len();
+            match this.inner[i].as_mut().poll_effect(cx) {
+                EffectResult::Item(x) => {
+                    if <E::Produces as Iterable>::MULTIPLE {
+                        this.next = i + 1;
+                    } else {
+                        drop(this.inner.remove(i));
+                        this.next = i;
+                    }
+                    return EffectResult::Item(x);
+                }
+                EffectResult::Failure(x) => {
+                    this.inner.clear();
+                    return EffectResult::Failure(x);
+                }
+                EffectResult::Done(_) => {
+                    // the next effective has shifted into this position
+                    drop(this.inner.remove(i));
+                    if i < this.next {
+                        this.next -= 1;
+                    }
+                }
+                EffectResult::Pending(x) => {
+                    pending = Some(x);
+                    polled += 1;
+                }
+            }
+        }
+
+        match pending {
+            Some(x) => EffectResult::Pending(x),
+            None => EffectResult::Done(Multiple),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.iter().fold((0, Some(0)), |(lower, upper), e| {
+            let (l, u) = e.size_hint();
+            let upper = match (upper, u) {
+                (Some(x), Some(y)) => x.checked_add(y),
+                _ => None,
+            };
+            (lower.saturating_add(l), upper)
+        })
+    }
+}
@@ -1,29 +1,58 @@
 //! Where common [`Effective`] adaptors live
 
-use std::{convert::Infallible, future::Future, pin::pin, task::Context};
+use std::{
+    convert::Infallible,
+    future::Future,
+    pin::pin,
+    task::Context,
+    time::{Duration, Instant},
+};
 
 use futures_util::task::noop_waker_ref;
 
 use crate::{
-    utils::{AsyncWith, FallibleWith, IterableWith},
+    timer::Timer,
+    utils::{AsyncWith, FallibleWith, IterableWith, LiftResult},
     wrappers::{FromFallible, FromFuture, FromIterator},
-    Async, Blocking, EffectResult, Effective, Failure, Multiple, Shim, SimpleTry, Single,
+    Async, Blocking, BoxEffective, EffectResult, Effective, Failure, LocalBoxEffective, Multiple,
+    Shim, SimpleTry, Single,
 };
 
-use self::blocking::Executor;
+use self::blocking::{Executor, ParkExecutor};
 
+pub mod aggregate;
+pub mod and_then;
 pub mod blocking;
+pub mod buffered;
+pub mod chain;
+pub mod chunks;
 pub mod collect;
+pub mod filter;
 pub mod flatten;
 pub mod fold;
 pub mod for_each;
+pub mod fuse;
+pub mod join;
+pub mod limit;
 pub mod map;
+pub mod map_err;
+pub mod merge;
+pub mod peekable;
+pub mod result;
+pub mod retry;
+pub mod search;
+pub mod timeout;
 pub mod unwrap;
+pub mod zip;
 
 pub type FromTryFn<T> = fn(T) -> FromFallible<T>;
 pub type FromIterFn<T> = fn(T) -> FromIterator<T>;
 pub type FromFutFn<T> = fn(T) -> FromFuture<T>;
 pub type FlatMap<E, F> = flatten::Flatten<map::Map<E, F>>;
+pub type ErrIntoFn<T, U> = fn(T) -> U;
+pub type ZipFn<A, B> = fn(A, B) -> (A, B);
+pub type Zip<E1, E2> =
+    zip::ZipWith<E1, E2, ZipFn<<E1 as Effective>::Item, <E2 as Effective>::Item>>;
 
 /// Common adaptors to [`Effective`].
 pub trait EffectiveExt: Effective {
@@ -128,6 +157,124 @@ pub trait EffectiveExt: Effective {
         }
     }
 
+    /// Map the items in the effective with a function that returns a new effective.
+    ///
+    /// `F` must return a new effective, this must only have a single value but can be async or fallible.
+    /// Unlike [`flat_map`](EffectiveExt::flat_map), the effective returned is not flattened,
+    /// so this keeps the iterable effect of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// async fn double(x: i32) -> i32 {
+    ///     x * 2
+    /// }
+    ///
+    /// let e = wrappers::iterator([1, 2, 3, 4]);
+    ///
+    /// let v: Vec<i32> = e
+    ///     .then(|x| wrappers::future(double(x)))
+    ///     .collect()
+    ///     .block_on(runtime)
+    ///     .get();
+    /// assert_eq!(v, [2, 4, 6, 8]);
+    /// ```
+    fn then<F, C>(self, f: F) -> map::Then<Self, F, C>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> C,
+        C: Effective<Produces = Single>,
+        Self::Async: AsyncWith<C::Async>,
+        Self::Failure: FallibleWith<C::Failure>,
+    {
+        map::Then {
+            inner: self,
+            func: f,
+            eff: None,
+        }
+    }
+
+    /// Pair each item with its index, starting at 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator(["a", "b", "c"]);
+    ///
+    /// let v: Vec<(usize, &str)> = e.enumerate().collect().get();
+    /// assert_eq!(v, [(0, "a"), (1, "b"), (2, "c")]);
+    /// ```
+    fn enumerate(self) -> map::Enumerate<Self>
+    where
+        Self: Sized,
+    {
+        map::Enumerate {
+            inner: self,
+            count: 0,
+        }
+    }
+
+    /// Call a function with a reference to each item, passing the item on unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([1, 2, 3]);
+    ///
+    /// let mut seen = vec![];
+    /// let v: i32 = e.inspect(|x| seen.push(*x)).sum().get();
+    /// assert_eq!(v, 6);
+    /// assert_eq!(seen, [1, 2, 3]);
+    /// ```
+    fn inspect<F>(self, f: F) -> map::Inspect<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item),
+    {
+        map::Inspect {
+            inner: self,
+            inspect: f,
+        }
+    }
+
+    /// Map the items while carrying some state, as with [`Iterator::scan`].
+    ///
+    /// The effective stops as soon as `f` returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([1, 2, 3, 4, 5]);
+    ///
+    /// let v: Vec<i32> = e
+    ///     .scan(0, |total, x| {
+    ///         *total += x;
+    ///         (*total < 10).then_some(*total)
+    ///     })
+    ///     .collect()
+    ///     .get();
+    /// assert_eq!(v, [1, 3, 6]);
+    /// ```
+    fn scan<S, R, F>(self, initial: S, f: F) -> map::Scan<Self, S, F>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        F: FnMut(&mut S, Self::Item) -> Option<R>,
+    {
+        map::Scan {
+            inner: Some(self),
+            state: initial,
+            func: f,
+        }
+    }
+
     /// If this effective item is itself an effective, flatten those items into a single effective.
     fn flatten(self) -> flatten::Flatten<Self>
     where
@@ -143,9 +290,9 @@ pub trait EffectiveExt: Effective {
         }
     }
 
-    /// Collect the items from this iterator into a collection.
+    /// Only keep the items that match the predicate.
     ///
-    /// Can be thought of as subtracting the 'iterable' effect.
+    /// The result can always produce multiple values, since a [`Single`] item might be dropped.
     ///
     /// # Example
     ///
@@ -153,23 +300,45 @@ pub trait EffectiveExt: Effective {
     /// use effective::{impls::EffectiveExt, wrappers};
     /// let e = wrappers::iterator([1, 2, 3, 4]);
     ///
-    /// let v: Vec<i32> = e.collect().get();
+    /// let v: Vec<i32> = e.filter(|x| x % 2 == 0).collect().get();
+    /// assert_eq!(v, [2, 4]);
     /// ```
-    fn collect<C>(self) -> collect::Collect<Self, C>
+    fn filter<P>(self, predicate: P) -> filter::Filter<Self, P>
     where
         Self: Sized,
-        Self: Effective<Produces = Multiple>,
-        C: Default + Extend<Self::Item>,
+        P: FnMut(&Self::Item) -> bool,
     {
-        collect::Collect {
-            inner: self,
-            into: Default::default(),
+        filter::Filter {
+            inner: Some(self),
+            predicate,
         }
     }
 
-    /// Block on the async effective
+    /// Map the items in the effective, only keeping the items that return `Some`.
     ///
-    /// Can be thought of as subtracting the 'async' effect.
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator(["1", "two", "3"]);
+    ///
+    /// let v: Vec<i32> = e.filter_map(|x| x.parse::<i32>().ok()).collect().get();
+    /// assert_eq!(v, [1, 3]);
+    /// ```
+    fn filter_map<R, F>(self, f: F) -> filter::FilterMap<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Option<R>,
+    {
+        filter::FilterMap {
+            inner: Some(self),
+            func: f,
+        }
+    }
+
+    /// Only keep the items that match the predicate.
+    ///
+    /// `P` must return a new effective, this must only have a single value but can be async or fallible.
     ///
     /// # Example
     ///
@@ -178,84 +347,1552 @@ pub trait EffectiveExt: Effective {
     ///
     /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
     ///
-    /// let e = wrappers::iterator([1, 2, 3, 4])
-    ///     .map(|x| async move { x + 1 })
-    ///     .flatten_future();
+    /// async fn is_even(x: i32) -> bool {
+    ///     x % 2 == 0
+    /// }
     ///
-    /// let v: Vec<i32> = e.block_on(runtime).collect().get();
-    /// assert_eq!(v, [2, 3, 4, 5]);
+    /// let e = wrappers::iterator([1, 2, 3, 4]);
+    ///
+    /// let v: Vec<i32> = e
+    ///     .filter_effect(|&x| wrappers::future(is_even(x)))
+    ///     .collect()
+    ///     .block_on(runtime)
+    ///     .get();
+    /// assert_eq!(v, [2, 4]);
     /// ```
-    fn block_on<R>(self, executor: R) -> blocking::Block<Self, R>
+    fn filter_effect<P, C>(self, predicate: P) -> filter::FilterEffect<Self, P, C>
     where
         Self: Sized,
-        Self: Effective<Async = Async>,
-        R: Executor,
+        P: FnMut(&Self::Item) -> C,
+        C: Effective<Item = bool, Produces = Single>,
+        Self::Async: AsyncWith<C::Async>,
+        Self::Failure: FallibleWith<C::Failure>,
     {
-        blocking::Block {
-            inner: self,
-            executor,
+        filter::FilterEffect {
+            inner: Some(self),
+            predicate,
+            state: filter::State::Acc,
         }
     }
 
-    /// Panic on the fallible effective
+    /// Produce all the items from this effective, followed by all the items from `other`.
     ///
-    /// Can be thought of as subtracting the 'fallable' effect.
+    /// The effects of both are combined, so a blocking effective can be chained
+    /// with an async or fallible effective.
     ///
     /// # Example
     ///
     /// ```
     /// use effective::{impls::EffectiveExt, wrappers};
     ///
-    /// let e = wrappers::from_fn_once(|| 1_i32)
-    ///     .map(|x| x.checked_add(1))
-    ///     .flatten_fallible();
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
     ///
-    /// let v = e.unwrap().get();
-    /// assert_eq!(v, 2);
+    /// async fn get_remote() -> Result<i32, std::io::Error> {
+    ///     /* insert http request */
+    /// #   Ok(3)
+    /// }
+    ///
+    /// let cached = wrappers::iterator([1, 2]);
+    /// let remote = wrappers::future(get_remote()).flatten_fallible();
+    ///
+    /// let v: Result<Vec<i32>, _> = cached.chain(remote).collect().block_on(runtime).try_get();
+    /// assert_eq!(v.unwrap(), [1, 2, 3]);
     /// ```
-    fn unwrap(self) -> unwrap::Unwrap<Self>
+    fn chain<E>(self, other: E) -> chain::Chain<Self, E>
     where
         Self: Sized,
-        Self::Failure: std::fmt::Debug,
+        E: Effective<Item = Self::Item>,
+        Self::Async: AsyncWith<E::Async>,
+        Self::Failure: FallibleWith<E::Failure>,
     {
-        unwrap::Unwrap { inner: self }
+        chain::Chain {
+            first: Some(self),
+            second: Some(other),
+        }
     }
 
-    /// Extract the value if there are no more effects possible
-    fn get(self) -> Self::Item
+    /// Interleave the items of this effective with the items of `other`,
+    /// producing whichever is ready first.
+    ///
+    /// The two sides take turns being polled first, so neither can starve the other.
+    /// The effects of both are combined, so merging two blocking effectives stays blocking.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let a = wrappers::iterator([1, 2, 3]);
+    /// let b = wrappers::iterator([4, 5]);
+    ///
+    /// let v: Vec<i32> = a.merge(b).collect().get();
+    /// assert_eq!(v, [1, 4, 2, 5, 3]);
+    /// ```
+    fn merge<E>(self, other: E) -> merge::Merge<Self, E>
     where
         Self: Sized,
-        Self: Effective<Produces = Single, Failure = Infallible, Async = Blocking>,
+        E: Effective<Item = Self::Item>,
+        Self::Async: AsyncWith<E::Async>,
+        Self::Failure: FallibleWith<E::Failure>,
     {
-        match pin!(self).poll_effect(&mut Context::from_waker(noop_waker_ref())) {
-            EffectResult::Item(x) => x,
-            EffectResult::Failure(x) => match x {},
-            EffectResult::Done(x) => match x {},
-            EffectResult::Pending(x) => match x {},
+        merge::Merge {
+            first: Some(self),
+            second: Some(other),
+            second_turn: false,
         }
     }
 
-    /// Extract the value or failure
-    fn try_get<R, F>(self) -> R
+    /// Pair up the items of this effective with the items of `other`.
+    ///
+    /// Zipping two [`Single`] effectives produces a single pair, otherwise the pairs stop
+    /// as soon as either side runs out. Both sides are polled concurrently
+    /// and the effects of both are combined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// let ids = wrappers::iterator([1, 2, 3]);
+    /// let names = wrappers::iterator(["a", "b"])
+    ///     .map(|x| async move { x.to_uppercase() })
+    ///     .flatten_future();
+    ///
+    /// let v: Vec<(i32, String)> = ids.zip(names).collect().block_on(runtime).get();
+    /// assert_eq!(v, [(1, "A".to_owned()), (2, "B".to_owned())]);
+    ///
+    /// // once the first side runs out, no more items are taken from the second side
+    /// let mut taken = 0;
+    /// let e = wrappers::iterator([1, 2]).zip(wrappers::iterator(0..10).inspect(|_| taken += 1));
+    /// assert_eq!(e.count().get(), 2);
+    /// assert_eq!(taken, 2);
+    /// ```
+    fn zip<E>(self, other: E) -> Zip<Self, E>
     where
         Self: Sized,
-        Self: Effective<Produces = Single, Async = Blocking, Failure = Failure<F>>,
-        R: SimpleTry<Continue = Self::Item, Break = F>,
+        E: Effective,
+        Self::Produces: IterableWith<E::Produces>,
+        Self::Async: AsyncWith<E::Async>,
+        Self::Failure: FallibleWith<E::Failure>,
     {
-        match pin!(self).poll_effect(&mut Context::from_waker(noop_waker_ref())) {
-            EffectResult::Item(x) => R::from_continue(x),
-            EffectResult::Failure(x) => R::from_break(x.0),
-            EffectResult::Done(x) => match x {},
-            EffectResult::Pending(_) => unimplemented!(),
+        self.zip_with(other, |a, b| (a, b))
+    }
+
+    /// Pair up the items of this effective with the items of `other`,
+    /// combining them with the function.
+    ///
+    /// See [`zip()`](EffectiveExt::zip) for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let a = wrappers::once(1);
+    /// let b = wrappers::once(2);
+    ///
+    /// let v: i32 = a.zip_with(b, |a, b| a + b).get();
+    /// assert_eq!(v, 3);
+    /// ```
+    fn zip_with<R, E, F>(self, other: E, f: F) -> zip::ZipWith<Self, E, F>
+    where
+        Self: Sized,
+        E: Effective,
+        F: FnMut(Self::Item, E::Item) -> R,
+        Self::Produces: IterableWith<E::Produces>,
+        Self::Async: AsyncWith<E::Async>,
+        Self::Failure: FallibleWith<E::Failure>,
+    {
+        zip::ZipWith {
+            first: Some(self),
+            second: Some(other),
+            left: None,
+            right: None,
+            func: f,
         }
     }
 
-    /// Return a [`shim`](Shim) that implements either [`Future`], [`Stream`](futures_core::stream::Stream) or [`Iterator`]
-    fn shim(self) -> Shim<Self>
+    /// Only produce the first `n` items. The inner effective is dropped as soon as
+    /// the `n`th item is produced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator(1..);
+    ///
+    /// let v: Vec<i32> = e.take(3).collect().get();
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    fn take(self, n: usize) -> limit::Take<Self>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+    {
+        limit::Take {
+            inner: Some(self),
+            remaining: n,
+        }
+    }
+
+    /// Skip the first `n` items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([1, 2, 3, 4]);
+    ///
+    /// let v: Vec<i32> = e.skip(2).collect().get();
+    /// assert_eq!(v, [3, 4]);
+    /// ```
+    fn skip(self, n: usize) -> limit::Skip<Self>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+    {
+        limit::Skip {
+            inner: Some(self),
+            remaining: n,
+        }
+    }
+
+    /// Produce items while they match the predicate. The inner effective is dropped as soon as
+    /// an item fails to match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([1, 2, 3, 4, 1]);
+    ///
+    /// let v: Vec<i32> = e.take_while(|x| *x < 3).collect().get();
+    /// assert_eq!(v, [1, 2]);
+    /// ```
+    fn take_while<P>(self, predicate: P) -> limit::TakeWhile<Self, P>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        limit::TakeWhile {
+            inner: Some(self),
+            predicate,
+        }
+    }
+
+    /// Skip items while they match the predicate. Once an item fails to match,
+    /// all the following items are produced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([1, 2, 3, 4, 1]);
+    ///
+    /// let v: Vec<i32> = e.skip_while(|x| *x < 3).collect().get();
+    /// assert_eq!(v, [3, 4, 1]);
+    /// ```
+    fn skip_while<P>(self, predicate: P) -> limit::SkipWhile<Self, P>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        limit::SkipWhile {
+            inner: Some(self),
+            predicate,
+            skipping: true,
+        }
+    }
+
+    /// Produce the first item, and then every `step`th item after that.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([1, 2, 3, 4, 5, 6]);
+    ///
+    /// let v: Vec<i32> = e.step_by(2).collect().get();
+    /// assert_eq!(v, [1, 3, 5]);
+    /// ```
+    fn step_by(self, step: usize) -> limit::StepBy<Self>
     where
         Self: Sized,
+        Self: Effective<Produces = Multiple>,
     {
-        Shim { inner: self }
+        assert!(step != 0, "step must be non-zero");
+        limit::StepBy {
+            inner: Some(self),
+            step,
+            skip: 0,
+        }
+    }
+
+    /// Group the items into batches of `n`. The last batch may be smaller.
+    ///
+    /// If the upstream fails, the partial batch is produced before the failure.
+    /// This can be configured with [`on_failure()`](chunks::Chunks::on_failure).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::{chunks::OnFailure, EffectiveExt}, wrappers};
+    /// let e = wrappers::iterator([1, 2, 3, 4, 5]);
+    ///
+    /// let v: Vec<Vec<i32>> = e.chunks(2).collect().get();
+    /// assert_eq!(v, [vec![1, 2], vec![3, 4], vec![5]]);
+    ///
+    /// // with no limit, everything ends up in one batch
+    /// let v: Vec<Vec<i32>> = wrappers::iterator([1, 2, 3]).chunks(usize::MAX).collect().get();
+    /// assert_eq!(v, [vec![1, 2, 3]]);
+    ///
+    /// let e = wrappers::try_iterator(["1", "2", "x", "4"].map(|x| x.parse::<i32>()));
+    /// let v: Vec<_> = e.chunks(3).shim().collect();
+    /// assert!(matches!(v[..], [Ok(ref batch), Err(_)] if batch == &[1, 2]));
+    ///
+    /// let e = wrappers::try_iterator(["1", "2", "x", "4"].map(|x| x.parse::<i32>()));
+    /// let v: Vec<_> = e.chunks(3).on_failure(OnFailure::Discard).shim().collect();
+    /// assert!(matches!(v[..], [Err(_)]));
+    /// ```
+    fn chunks(self, n: usize) -> chunks::Chunks<Self>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+    {
+        chunks::Chunks {
+            inner: Some(self),
+            batch: chunks::Batch::new(n),
+        }
+    }
+
+    /// Group the items into batches of up to `n`, producing a smaller batch
+    /// whenever the upstream is not ready with more items.
+    ///
+    /// If the upstream fails, the partial batch is produced before the failure.
+    /// This can be configured with [`on_failure()`](chunks::ReadyChunks::on_failure).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// use futures_util::{stream, StreamExt};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// let s = stream::iter([1, 2, 3]).chain(stream::once(async {
+    ///     tokio::task::yield_now().await;
+    ///     4
+    /// }));
+    /// let e = wrappers::stream(s);
+    ///
+    /// let v: Vec<Vec<i32>> = e.ready_chunks(5).collect().block_on(runtime).get();
+    /// assert_eq!(v, [vec![1, 2, 3], vec![4]]);
+    /// ```
+    fn ready_chunks(self, n: usize) -> chunks::ReadyChunks<Self>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple, Async = Async>,
+    {
+        chunks::ReadyChunks {
+            inner: Some(self),
+            batch: chunks::Batch::new(n),
+        }
+    }
+
+    /// Group the items into batches of up to `n`, producing a smaller batch
+    /// once `duration` has passed since the first item of the batch.
+    ///
+    /// The sleeps are created by the given [`Timer`].
+    /// If the upstream fails, the partial batch is produced before the failure.
+    /// This can be configured with [`on_failure()`](chunks::ChunksTimeout::on_failure).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::{pin::pin, task::Context, time::Duration};
+    /// use effective::{impls::EffectiveExt, timer::ManualTimer, wrappers, EffectResult, Effective};
+    /// use futures_util::{stream, task::noop_waker_ref, StreamExt};
+    ///
+    /// let timer = ManualTimer::new();
+    /// let e = wrappers::stream(stream::iter([1, 2, 3]).chain(stream::pending()));
+    /// let mut e = pin!(e.chunks_timeout(2, Duration::from_secs(10), timer.clone()));
+    /// let mut cx = Context::from_waker(noop_waker_ref());
+    ///
+    /// // a full batch is produced straight away
+    /// assert!(matches!(e.as_mut().poll_effect(&mut cx), EffectResult::Item(v) if v == [1, 2]));
+    ///
+    /// // the partial batch waits for the timer
+    /// assert!(matches!(e.as_mut().poll_effect(&mut cx), EffectResult::Pending(_)));
+    /// timer.advance(Duration::from_secs(9));
+    /// assert!(matches!(e.as_mut().poll_effect(&mut cx), EffectResult::Pending(_)));
+    ///
+    /// // and is flushed once the timer fires
+    /// timer.advance(Duration::from_secs(1));
+    /// assert!(matches!(e.as_mut().poll_effect(&mut cx), EffectResult::Item(v) if v == [3]));
+    /// assert!(matches!(e.as_mut().poll_effect(&mut cx), EffectResult::Pending(_)));
+    /// ```
+    fn chunks_timeout<T>(
+        self,
+        n: usize,
+        duration: Duration,
+        timer: T,
+    ) -> chunks::ChunksTimeout<Self, T>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple, Async = Async>,
+        T: Timer,
+    {
+        chunks::ChunksTimeout {
+            inner: Some(self),
+            batch: chunks::Batch::new(n),
+            timer,
+            duration,
+            sleep: None,
+        }
+    }
+
+    /// Fail with [`Elapsed`](timeout::Elapsed) if this effective waits for longer than `duration`.
+    ///
+    /// If this produces multiple items, the limit applies to each item separately.
+    /// Once the limit is reached, the upstream is dropped.
+    ///
+    /// The failure is combined with any existing failure, which must implement `From<Elapsed>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::{pin::pin, task::Context, time::Duration};
+    /// use effective::{impls::EffectiveExt, timer::ManualTimer, wrappers, EffectResult, Effective};
+    /// use futures_util::task::noop_waker_ref;
+    ///
+    /// let timer = ManualTimer::new();
+    /// let e = wrappers::future(std::future::pending::<i32>());
+    /// let mut e = pin!(e.timeout(Duration::from_secs(5), timer.clone()));
+    /// let mut cx = Context::from_waker(noop_waker_ref());
+    ///
+    /// assert!(matches!(e.as_mut().poll_effect(&mut cx), EffectResult::Pending(_)));
+    /// timer.advance(Duration::from_secs(5));
+    /// assert!(matches!(e.as_mut().poll_effect(&mut cx), EffectResult::Failure(_)));
+    /// ```
+    fn timeout<T>(self, duration: Duration, timer: T) -> timeout::Timeout<Self, T>
+    where
+        Self: Sized,
+        Self: Effective<Async = Async>,
+        T: Timer,
+        Failure<timeout::Elapsed>: FallibleWith<Self::Failure>,
+    {
+        timeout::Timeout {
+            inner: Some(self),
+            timer,
+            limit: timeout::Limit::Timeout(duration),
+            sleep: None,
+        }
+    }
+
+    /// Fail with [`Elapsed`](timeout::Elapsed) if this effective is still waiting at `deadline`.
+    ///
+    /// If this produces multiple items, the deadline applies to all of them.
+    /// Once the deadline is reached, the upstream is dropped.
+    ///
+    /// The failure is combined with any existing failure, which must implement `From<Elapsed>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use effective::{impls::EffectiveExt, timer::TokioTimer, wrappers};
+    /// use futures_util::{stream, StreamExt};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread()
+    ///     .enable_time()
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let s = stream::iter([1, 2]).chain(stream::once(async {
+    ///     tokio::time::sleep(Duration::from_secs(60)).await;
+    ///     3
+    /// }));
+    /// let e = wrappers::stream(s).deadline(Instant::now() + Duration::from_millis(10), TokioTimer);
+    ///
+    /// let v: Vec<_> = runtime.block_on(e.shim().collect());
+    /// assert!(matches!(v[..], [Ok(1), Ok(2), Err(_)]));
+    /// ```
+    fn deadline<T>(self, deadline: Instant, timer: T) -> timeout::Timeout<Self, T>
+    where
+        Self: Sized,
+        Self: Effective<Async = Async>,
+        T: Timer,
+        Failure<timeout::Elapsed>: FallibleWith<Self::Failure>,
+    {
+        timeout::Timeout {
+            inner: Some(self),
+            timer,
+            limit: timeout::Limit::Deadline(deadline),
+            sleep: None,
+        }
+    }
+
+    /// If the items of this effective are themselves effectives, run up to `n` of them at once,
+    /// producing their items in the original order.
+    ///
+    /// The first failure from any of them is returned immediately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// async fn get_page(x: usize) -> Result<String, std::io::Error> {
+    ///     /* insert http request */
+    /// #   Ok(x.to_string())
+    /// }
+    ///
+    /// let e = wrappers::iterator([1, 2, 3, 4])
+    ///     .map(|x| wrappers::future(get_page(x)).flatten_fallible())
+    ///     .buffered(2);
+    ///
+    /// let v: Result<Vec<String>, _> = e.collect().block_on(runtime).try_get();
+    /// assert_eq!(v.unwrap(), ["1", "2", "3", "4"]);
+    /// ```
+    fn buffered(self, n: usize) -> buffered::Buffered<Self>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        Self::Item: Effective<Produces = Single>,
+        Self::Async: AsyncWith<<Self::Item as Effective>::Async>,
+        Self::Failure: FallibleWith<<Self::Item as Effective>::Failure>,
+    {
+        assert!(n != 0, "buffer size must be non-zero");
+        buffered::Buffered {
+            inner: Some(self),
+            in_flight: std::collections::VecDeque::new(),
+            limit: n,
+        }
+    }
+
+    /// If the items of this effective are themselves effectives, run up to `n` of them at once,
+    /// producing their items as soon as they are ready.
+    ///
+    /// The first failure from any of them is returned immediately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// let e = wrappers::iterator([1, 2, 3, 4])
+    ///     .map(|x| wrappers::future(async move { x * 2 }))
+    ///     .buffer_unordered(2);
+    ///
+    /// let mut v: Vec<i32> = e.collect().block_on(&runtime).get();
+    /// v.sort();
+    /// assert_eq!(v, [2, 4, 6, 8]);
+    ///
+    /// // with no limit, they all run at once
+    /// let e = wrappers::iterator([1, 2, 3, 4])
+    ///     .map(|x| wrappers::future(async move { x * 2 }))
+    ///     .buffer_unordered(usize::MAX);
+    ///
+    /// let v: i32 = e.sum().block_on(&runtime).get();
+    /// assert_eq!(v, 20);
+    /// ```
+    fn buffer_unordered(self, n: usize) -> buffered::BufferUnordered<Self>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        Self::Item: Effective<Produces = Single>,
+        Self::Async: AsyncWith<<Self::Item as Effective>::Async>,
+        Self::Failure: FallibleWith<<Self::Item as Effective>::Failure>,
+    {
+        assert!(n != 0, "buffer size must be non-zero");
+        buffered::BufferUnordered {
+            inner: Some(self),
+            in_flight: Vec::new(),
+            limit: n,
+        }
+    }
+
+    /// Collect the items from this iterator into a collection.
+    ///
+    /// Can be thought of as subtracting the 'iterable' effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([1, 2, 3, 4]);
+    ///
+    /// let v: Vec<i32> = e.collect().get();
+    /// ```
+    fn collect<C>(self) -> collect::Collect<Self, C>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        C: Default + Extend<Self::Item>,
+    {
+        collect::Collect {
+            inner: self,
+            into: Default::default(),
+        }
+    }
+
+    /// Count the items produced by this iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(e.count().get(), 4);
+    /// ```
+    fn count(self) -> aggregate::Count<Self>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+    {
+        aggregate::Aggregate {
+            inner: self,
+            acc: aggregate::CountAcc(0),
+        }
+    }
+
+    /// Add together the items produced by this iterator, as with [`Iterator::sum`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// let e = wrappers::iterator([1, 2, 3, 4])
+    ///     .map(|x| wrappers::future(async move { x * 2 }))
+    ///     .buffered(2);
+    ///
+    /// let sum: i32 = e.sum().block_on(runtime).get();
+    /// assert_eq!(sum, 20);
+    /// ```
+    fn sum<S>(self) -> aggregate::Sum<Self, S>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        S: std::iter::Sum<Self::Item> + std::iter::Sum<S>,
+    {
+        aggregate::Aggregate {
+            inner: self,
+            acc: aggregate::SumAcc(None),
+        }
+    }
+
+    /// Multiply together the items produced by this iterator, as with [`Iterator::product`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([1, 2, 3, 4]);
+    ///
+    /// let product: i32 = e.product().get();
+    /// assert_eq!(product, 24);
+    /// ```
+    fn product<S>(self) -> aggregate::Product<Self, S>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        S: std::iter::Product<Self::Item> + std::iter::Product<S>,
+    {
+        aggregate::Aggregate {
+            inner: self,
+            acc: aggregate::ProductAcc(None),
+        }
+    }
+
+    /// Find the minimum item produced by this iterator.
+    ///
+    /// If several items are equally minimum, the first one is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([3, 1, 4, 1, 5]);
+    ///
+    /// assert_eq!(e.min().get(), Some(1));
+    /// ```
+    fn min(self) -> aggregate::Min<Self>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        Self::Item: Ord,
+    {
+        aggregate::Aggregate {
+            inner: self,
+            acc: aggregate::MinAcc(None),
+        }
+    }
+
+    /// Find the maximum item produced by this iterator.
+    ///
+    /// If several items are equally maximum, the last one is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::try_iterator(["3", "1", "4"].map(|x| x.parse::<i32>()));
+    ///
+    /// let v: Result<Option<i32>, _> = e.max().try_get();
+    /// assert_eq!(v, Ok(Some(4)));
+    /// ```
+    fn max(self) -> aggregate::Max<Self>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        Self::Item: Ord,
+    {
+        aggregate::Aggregate {
+            inner: self,
+            acc: aggregate::MaxAcc(None),
+        }
+    }
+
+    /// Find the item with the minimum key, as computed by `key`.
+    ///
+    /// If several items are equally minimum, the first one is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator(["apple", "fig", "kiwi", "pea"]);
+    ///
+    /// assert_eq!(e.min_by_key(|x| x.len()).get(), Some("fig"));
+    /// ```
+    fn min_by_key<K, F>(self, key: F) -> aggregate::MinByKey<Self, K, F>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        aggregate::Aggregate {
+            inner: self,
+            acc: aggregate::MinByKeyAcc { min: None, key },
+        }
+    }
+
+    /// Find the item with the maximum key, as computed by `key`.
+    ///
+    /// If several items are equally maximum, the last one is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator(["apple", "fig", "kiwi", "mango"]);
+    ///
+    /// assert_eq!(e.max_by_key(|x| x.len()).get(), Some("mango"));
+    /// ```
+    fn max_by_key<K, F>(self, key: F) -> aggregate::MaxByKey<Self, K, F>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        aggregate::Aggregate {
+            inner: self,
+            acc: aggregate::MaxByKeyAcc { max: None, key },
+        }
+    }
+
+    /// Get the last item produced by this iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(e.last().get(), Some(4));
+    /// ```
+    fn last(self) -> aggregate::Last<Self>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+    {
+        aggregate::Aggregate {
+            inner: self,
+            acc: aggregate::LastAcc(None),
+        }
+    }
+
+    /// Allow looking at the next item without consuming it.
+    ///
+    /// See [`Peekable::peek`](peekable::Peekable::peek), [`Peekable::next_if`](peekable::Peekable::next_if)
+    /// and [`Peekable::next_if_eq`](peekable::Peekable::next_if_eq).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::pin;
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// let tokens = wrappers::stream(futures_util::stream::iter(["1", "+", "2", "+", "3"]));
+    ///
+    /// let sum = runtime.block_on(async {
+    ///     let mut tokens = pin!(tokens.peekable());
+    ///     let mut sum = 0;
+    ///     while let Some(x) = tokens.as_mut().next_if(|x| *x != "+").shim().await {
+    ///         sum += x.parse::<i32>().unwrap();
+    ///         if tokens.as_mut().next_if_eq(&"+").shim().await.is_none() {
+    ///             break;
+    ///         }
+    ///     }
+    ///     assert_eq!(tokens.peek().shim().await, None);
+    ///     sum
+    /// });
+    /// assert_eq!(sum, 6);
+    /// ```
+    fn peekable(self) -> peekable::Peekable<Self>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+    {
+        peekable::Peekable {
+            inner: Some(self),
+            peeked: None,
+        }
+    }
+
+    /// Find the first item that matches the predicate.
+    ///
+    /// The upstream is dropped as soon as a match is found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator(1..);
+    ///
+    /// assert_eq!(e.find(|x| x * x > 20).get(), Some(5));
+    /// ```
+    fn find<P>(self, predicate: P) -> search::Find<Self, P>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        search::Search {
+            inner: Some(self),
+            predicate,
+            searcher: search::FindSearch,
+        }
+    }
+
+    /// Find the first item that matches the predicate.
+    ///
+    /// `P` must return a new effective, this must only have a single value but can be async or fallible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// async fn is_available(name: &'static str) -> Result<bool, std::io::Error> {
+    ///     Ok(name.len() > 3)
+    /// }
+    ///
+    /// let e = wrappers::iterator(["foo", "bar", "quux", "corge"]);
+    ///
+    /// let v: Result<Option<&str>, _> = e
+    ///     .find_effect(|&x| wrappers::try_future(is_available(x)))
+    ///     .block_on(runtime)
+    ///     .try_get();
+    /// assert_eq!(v.unwrap(), Some("quux"));
+    /// ```
+    fn find_effect<P, C>(self, predicate: P) -> search::FindEffect<Self, P, C>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        P: FnMut(&Self::Item) -> C,
+        C: Effective<Item = bool, Produces = Single>,
+        Self::Async: AsyncWith<C::Async>,
+        Self::Failure: FallibleWith<C::Failure>,
+    {
+        search::SearchEffect {
+            inner: Some(self),
+            predicate,
+            searcher: search::FindSearch,
+            state: search::State::Acc,
+        }
+    }
+
+    /// Find the first item that `func` maps to a value, and return that value.
+    ///
+    /// The upstream is dropped as soon as a value is found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator(["a", "1", "b", "2"]);
+    ///
+    /// assert_eq!(e.find_map(|x| x.parse::<i32>().ok()).get(), Some(1));
+    /// ```
+    fn find_map<R, F>(self, func: F) -> search::FindMap<Self, F>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        F: FnMut(Self::Item) -> Option<R>,
+    {
+        search::Search {
+            inner: Some(self),
+            predicate: |_| true,
+            searcher: search::FindMapSearch(func),
+        }
+    }
+
+    /// Find the index of the first item that matches the predicate.
+    ///
+    /// The upstream is dropped as soon as a match is found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([1, 3, 4, 5]);
+    ///
+    /// assert_eq!(e.position(|x| x % 2 == 0).get(), Some(2));
+    /// ```
+    fn position<P>(self, predicate: P) -> search::Position<Self, P>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        search::Search {
+            inner: Some(self),
+            predicate,
+            searcher: search::PositionSearch(0),
+        }
+    }
+
+    /// Find the index of the first item that matches the predicate.
+    ///
+    /// `P` must return a new effective, this must only have a single value but can be async or fallible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// async fn is_even(x: i32) -> bool {
+    ///     x % 2 == 0
+    /// }
+    ///
+    /// let e = wrappers::iterator([1, 3, 4, 5]);
+    ///
+    /// let v = e
+    ///     .position_effect(|&x| wrappers::future(is_even(x)))
+    ///     .block_on(runtime)
+    ///     .get();
+    /// assert_eq!(v, Some(2));
+    /// ```
+    fn position_effect<P, C>(self, predicate: P) -> search::PositionEffect<Self, P, C>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        P: FnMut(&Self::Item) -> C,
+        C: Effective<Item = bool, Produces = Single>,
+        Self::Async: AsyncWith<C::Async>,
+        Self::Failure: FallibleWith<C::Failure>,
+    {
+        search::SearchEffect {
+            inner: Some(self),
+            predicate,
+            searcher: search::PositionSearch(0),
+            state: search::State::Acc,
+        }
+    }
+
+    /// Check whether any item matches the predicate.
+    ///
+    /// The upstream is dropped as soon as a match is found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([1, 3, 4, 5]);
+    ///
+    /// assert!(e.any(|x| x % 2 == 0).get());
+    /// ```
+    fn any<P>(self, predicate: P) -> search::Any<Self, P>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        search::Search {
+            inner: Some(self),
+            predicate,
+            searcher: search::AnySearch,
+        }
+    }
+
+    /// Check whether any item matches the predicate.
+    ///
+    /// `P` must return a new effective, this must only have a single value but can be async or fallible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// async fn is_even(x: i32) -> bool {
+    ///     x % 2 == 0
+    /// }
+    ///
+    /// let e = wrappers::iterator([1, 3, 5]);
+    ///
+    /// let v = e.any_effect(|&x| wrappers::future(is_even(x))).block_on(runtime).get();
+    /// assert!(!v);
+    /// ```
+    fn any_effect<P, C>(self, predicate: P) -> search::AnyEffect<Self, P, C>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        P: FnMut(&Self::Item) -> C,
+        C: Effective<Item = bool, Produces = Single>,
+        Self::Async: AsyncWith<C::Async>,
+        Self::Failure: FallibleWith<C::Failure>,
+    {
+        search::SearchEffect {
+            inner: Some(self),
+            predicate,
+            searcher: search::AnySearch,
+            state: search::State::Acc,
+        }
+    }
+
+    /// Check whether every item matches the predicate.
+    ///
+    /// The upstream is dropped as soon as an item does not match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([1, 3, 4, 5]);
+    ///
+    /// assert!(!e.all(|x| x % 2 == 1).get());
+    /// ```
+    fn all<P>(self, predicate: P) -> search::All<Self, P>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        search::Search {
+            inner: Some(self),
+            predicate,
+            searcher: search::AllSearch,
+        }
+    }
+
+    /// Check whether every item matches the predicate.
+    ///
+    /// `P` must return a new effective, this must only have a single value but can be async or fallible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// async fn is_odd(x: i32) -> bool {
+    ///     x % 2 == 1
+    /// }
+    ///
+    /// let e = wrappers::iterator([1, 3, 5]);
+    ///
+    /// let v = e.all_effect(|&x| wrappers::future(is_odd(x))).block_on(runtime).get();
+    /// assert!(v);
+    /// ```
+    fn all_effect<P, C>(self, predicate: P) -> search::AllEffect<Self, P, C>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+        P: FnMut(&Self::Item) -> C,
+        C: Effective<Item = bool, Produces = Single>,
+        Self::Async: AsyncWith<C::Async>,
+        Self::Failure: FallibleWith<C::Failure>,
+    {
+        search::SearchEffect {
+            inner: Some(self),
+            predicate,
+            searcher: search::AllSearch,
+            state: search::State::Acc,
+        }
+    }
+
+    /// Get the item at index `n`.
+    ///
+    /// The upstream is dropped as soon as the item is found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::iterator([1, 2, 3, 4]);
+    ///
+    /// assert_eq!(e.nth(2).get(), Some(3));
+    /// ```
+    fn nth(self, n: usize) -> search::Nth<Self>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Multiple>,
+    {
+        search::Search {
+            inner: Some(self),
+            predicate: |_| true,
+            searcher: search::NthSearch(n),
+        }
+    }
+
+    /// Make the effective safe to poll after it has completed.
+    ///
+    /// After a failure, the last item or `Done`, this will return `Done` if it produces
+    /// multiple items, or stay pending if it is async. See [`FusedEffective`](crate::FusedEffective).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::{pin::pin, task::Context};
+    /// use effective::{impls::EffectiveExt, wrappers, EffectResult, Effective, FusedEffective};
+    /// use futures_util::task::noop_waker_ref;
+    ///
+    /// let mut e = pin!(wrappers::iterator([1]).fuse());
+    /// let cx = &mut Context::from_waker(noop_waker_ref());
+    ///
+    /// assert!(matches!(e.as_mut().poll_effect(cx), EffectResult::Item(1)));
+    /// assert!(matches!(e.as_mut().poll_effect(cx), EffectResult::Done(_)));
+    /// assert!(e.is_terminated());
+    /// assert!(matches!(e.as_mut().poll_effect(cx), EffectResult::Done(_)));
+    /// ```
+    fn fuse(self) -> fuse::Fuse<Self>
+    where
+        Self: Sized,
+    {
+        fuse::Fuse { inner: Some(self) }
+    }
+
+    /// Block on the async effective
+    ///
+    /// Can be thought of as subtracting the 'async' effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// let e = wrappers::iterator([1, 2, 3, 4])
+    ///     .map(|x| async move { x + 1 })
+    ///     .flatten_future();
+    ///
+    /// let v: Vec<i32> = e.block_on(runtime).collect().get();
+    /// assert_eq!(v, [2, 3, 4, 5]);
+    /// ```
+    ///
+    /// The executor can also be borrowed, so one runtime can drive many effectives.
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// let a: i32 = wrappers::future(async { 1 }).block_on(&runtime).get();
+    /// let b: i32 = wrappers::future(async { 2 }).block_on(runtime.handle().clone()).get();
+    /// assert_eq!(a + b, 3);
+    /// ```
+    fn block_on<R>(self, executor: R) -> blocking::Block<Self, R>
+    where
+        Self: Sized,
+        Self: Effective<Async = Async>,
+        R: Executor,
+    {
+        blocking::Block {
+            inner: self,
+            executor,
+        }
+    }
+
+    /// Block on the async effective using the built-in [`ParkExecutor`],
+    /// which needs no runtime.
+    ///
+    /// Can be thought of as subtracting the 'async' effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let e = wrappers::iterator([1, 2, 3, 4])
+    ///     .map(|x| async move { x + 1 })
+    ///     .flatten_future();
+    ///
+    /// let v: Vec<i32> = e.block_on_current().collect().get();
+    /// assert_eq!(v, [2, 3, 4, 5]);
+    /// ```
+    fn block_on_current(self) -> blocking::Block<Self, ParkExecutor>
+    where
+        Self: Sized,
+        Self: Effective<Async = Async>,
+    {
+        self.block_on(ParkExecutor)
+    }
+
+    /// Panic on the fallible effective
+    ///
+    /// Can be thought of as subtracting the 'fallable' effect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let e = wrappers::from_fn_once(|| 1_i32)
+    ///     .map(|x| x.checked_add(1))
+    ///     .flatten_fallible();
+    ///
+    /// let v = e.unwrap().get();
+    /// assert_eq!(v, 2);
+    /// ```
+    fn unwrap(self) -> unwrap::Unwrap<Self>
+    where
+        Self: Sized,
+        Self::Failure: std::fmt::Debug,
+    {
+        unwrap::Unwrap { inner: self }
+    }
+
+    /// Move the failure into the items, as a [`Result`].
+    ///
+    /// Can be thought of as subtracting the 'fallible' effect, without panicking.
+    /// The failure is always the last item produced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let e = wrappers::iterator(["1", "2", "three", "4"])
+    ///     .map(|x| x.parse::<i32>())
+    ///     .from_result();
+    ///
+    /// let v: Vec<Result<i32, _>> = e.into_result().collect().get();
+    /// assert_eq!(v.len(), 3);
+    /// assert!(v[2].is_err());
+    /// ```
+    fn into_result<E>(self) -> result::IntoResult<Self>
+    where
+        Self: Sized,
+        Self: Effective<Failure = Failure<E>>,
+    {
+        result::IntoResult { inner: Some(self) }
+    }
+
+    /// If the `Item` of this effective is a [`Result`], it pulls the error into the failure effect.
+    ///
+    /// Unlike [`flatten_fallible()`](EffectiveExt::flatten_fallible), any existing failure type is kept
+    /// and the error is converted into it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let e = wrappers::fallible(Err::<&str, Box<dyn std::error::Error>>("boom".into()))
+    ///     .map(|x| x.parse::<i32>())
+    ///     .from_result();
+    ///
+    /// let v: Result<i32, Box<dyn std::error::Error>> = e.try_get();
+    /// assert_eq!(v.unwrap_err().to_string(), "boom");
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn from_result<T, E>(self) -> result::FromResult<Self>
+    where
+        Self: Sized,
+        Self: Effective<Item = Result<T, E>>,
+        Self::Failure: LiftResult<E>,
+    {
+        result::FromResult { inner: self }
+    }
+
+    /// Extract the value if there are no more effects possible
+    fn get(self) -> Self::Item
+    where
+        Self: Sized,
+        Self: Effective<Produces = Single, Failure = Infallible, Async = Blocking>,
+    {
+        match pin!(self).poll_effect(&mut Context::from_waker(noop_waker_ref())) {
+            EffectResult::Item(x) => x,
+            EffectResult::Failure(x) => match x {},
+            EffectResult::Done(x) => match x {},
+            EffectResult::Pending(x) => match x {},
+        }
+    }
+
+    /// Extract the value or failure
+    fn try_get<R, F>(self) -> R
+    where
+        Self: Sized,
+        Self: Effective<Produces = Single, Async = Blocking, Failure = Failure<F>>,
+        R: SimpleTry<Continue = Self::Item, Break = F>,
+    {
+        match pin!(self).poll_effect(&mut Context::from_waker(noop_waker_ref())) {
+            EffectResult::Item(x) => R::from_continue(x),
+            EffectResult::Failure(x) => R::from_break(x.0),
+            EffectResult::Done(x) => match x {},
+            EffectResult::Pending(_) => unimplemented!(),
+        }
+    }
+
+    /// Return a [`shim`](Shim) that implements either [`Future`], [`Stream`](futures_core::stream::Stream) or [`Iterator`]
+    ///
+    /// If the effective is fallible, the items are [`Result`]s, and the stream or iterator ends after
+    /// the first failure.
+    ///
+    /// The effective is [`fuse`](EffectiveExt::fuse)d first, so this returns a `Shim<Fuse<Self>>`
+    /// rather than a `Shim<Self>`. Build the [`Shim`] directly to avoid the fuse if the effective
+    /// is infallible or already a [`FusedEffective`](crate::FusedEffective).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// use futures_util::StreamExt;
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// let e = wrappers::iterator(["1", "2", "three", "4"])
+    ///     .map(|x| async move { x.parse::<i32>() })
+    ///     .flatten_future()
+    ///     .flatten_fallible();
+    ///
+    /// let v: Vec<Result<i32, _>> = runtime.block_on(e.shim().collect());
+    /// assert_eq!(v.len(), 3);
+    /// assert!(v[2].is_err());
+    /// ```
+    fn shim(self) -> Shim<fuse::Fuse<Self>>
+    where
+        Self: Sized,
+    {
+        Shim { inner: self.fuse() }
+    }
+
+    /// Erase the type of this effective, so it can be named and stored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::convert::Infallible;
+    /// use effective::{impls::EffectiveExt, wrappers, Blocking, BoxEffective, Multiple};
+    ///
+    /// fn evens(flip: bool) -> BoxEffective<'static, i32, Infallible, Multiple, Blocking> {
+    ///     if flip {
+    ///         wrappers::iterator([1, 2, 3, 4]).filter(|x| x % 2 == 0).boxed()
+    ///     } else {
+    ///         wrappers::iterator([2, 4]).boxed()
+    ///     }
+    /// }
+    ///
+    /// let v: Vec<i32> = evens(true).collect().get();
+    /// assert_eq!(v, [2, 4]);
+    /// ```
+    fn boxed<'a>(self) -> BoxEffective<'a, Self::Item, Self::Failure, Self::Produces, Self::Async>
+    where
+        Self: Sized + Send + 'a,
+    {
+        Box::pin(self)
+    }
+
+    /// Erase the type of this effective, so it can be named and stored.
+    ///
+    /// Unlike [`boxed()`](EffectiveExt::boxed), the effective does not need to be [`Send`].
+    fn boxed_local<'a>(
+        self,
+    ) -> LocalBoxEffective<'a, Self::Item, Self::Failure, Self::Produces, Self::Async>
+    where
+        Self: Sized + 'a,
+    {
+        Box::pin(self)
+    }
+
+    /// Map the failure in the effective
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::fallible("x".parse::<i32>());
+    ///
+    /// let v: Result<i32, String> = e.map_err(|e| e.to_string()).try_get();
+    /// assert_eq!(v.unwrap_err(), "invalid digit found in string");
+    /// ```
+    fn map_err<R, F, E>(self, f: F) -> map_err::MapErr<Self, F>
+    where
+        Self: Sized,
+        Self: Effective<Failure = Failure<E>>,
+        F: FnMut(E) -> R,
+    {
+        map_err::MapErr {
+            inner: self,
+            map: f,
+        }
+    }
+
+    /// Convert the failure in the effective using [`From`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    /// let e = wrappers::fallible("x".parse::<i32>());
+    ///
+    /// let v: Result<i32, Box<dyn std::error::Error>> = e.err_into().try_get();
+    /// assert!(v.is_err());
+    /// ```
+    fn err_into<U, E>(self) -> map_err::MapErr<Self, ErrIntoFn<E, U>>
+    where
+        Self: Sized,
+        Self: Effective<Failure = Failure<E>>,
+        U: From<E>,
+    {
+        self.map_err(U::from as _)
+    }
+
+    /// Run another effective with the item of this single effective.
+    ///
+    /// `F` must return a new effective, this must only have a single value but can be async or fallible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// async fn get_page(x: usize) -> Result<String, std::io::Error> {
+    ///     /* insert http request */
+    /// #   Ok(x.to_string())
+    /// }
+    ///
+    /// let e = wrappers::fallible("1".parse::<usize>()).and_then(|x| {
+    ///     wrappers::future(get_page(x))
+    ///         .flatten_fallible()
+    ///         .err_into::<Box<dyn std::error::Error>, _>()
+    /// });
+    ///
+    /// let v: Result<String, _> = e.block_on(runtime).try_get();
+    /// assert_eq!(v.unwrap(), "1");
+    /// ```
+    fn and_then<F, C>(self, f: F) -> and_then::AndThen<Self, F, C>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Single>,
+        F: FnOnce(Self::Item) -> C,
+        C: Effective<Produces = Single>,
+        Self::Async: AsyncWith<C::Async>,
+        Self::Failure: FallibleWith<C::Failure>,
+    {
+        and_then::AndThen {
+            state: and_then::State::First {
+                inner: self,
+                func: Some(f),
+            },
+        }
+    }
+
+    /// Recover from the failure of this single effective by running another effective.
+    ///
+    /// `F` must return a new effective, this must only have a single value but can be async or fallible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// async fn fallback() -> i32 {
+    ///     0
+    /// }
+    ///
+    /// let e = wrappers::fallible("x".parse::<i32>()).or_else(|_| wrappers::future(fallback()));
+    ///
+    /// let v: i32 = e.block_on(runtime).get();
+    /// assert_eq!(v, 0);
+    /// ```
+    fn or_else<F, C, E>(self, f: F) -> and_then::OrElse<Self, F, C>
+    where
+        Self: Sized,
+        Self: Effective<Produces = Single, Failure = Failure<E>>,
+        F: FnOnce(E) -> C,
+        C: Effective<Item = Self::Item, Produces = Single>,
+        Self::Async: AsyncWith<C::Async>,
+    {
+        and_then::OrElse {
+            state: and_then::State::First {
+                inner: self,
+                func: Some(f),
+            },
+        }
     }
 
     /// High level fold function. Takes all the items in the effective and applies the `func` to it,
@@ -0,0 +1,269 @@
+//! Effect adaptor that allows looking at the next item without consuming it
+
+use std::{pin::Pin, task::Context};
+
+use crate::{EffectResult, Effective, Multiple, Single};
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`peekable()`](super::EffectiveExt::peekable) method
+    pub struct Peekable<E>
+    where
+        E: Effective,
+    {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) peeked: Option<E::Item>,
+    }
+);
+
+impl<E> Peekable<E>
+where
+    E: Effective<Produces = Multiple>,
+{
+    /// Poll for a reference to the next item, without consuming it.
+    ///
+    /// Returns `Done` if there are no more items.
+    #[allow(clippy::type_complexity)]
+    pub fn poll_peek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> EffectResult<&E::Item, E::Failure, Multiple, E::Async> {
+        let mut this = self.project();
+        if this.peeked.is_none() {
+            let Some(inner) = this.inner.as_mut().as_pin_mut() else {
+                return EffectResult::Done(Multiple);
+            };
+            match inner.poll_effect(cx) {
+                EffectResult::Item(x) => *this.peeked = Some(x),
+                EffectResult::Failure(x) => return EffectResult::Failure(x),
+                EffectResult::Done(Multiple) => {
+                    this.inner.set(None);
+                    return EffectResult::Done(Multiple);
+                }
+                EffectResult::Pending(x) => return EffectResult::Pending(x),
+            }
+        }
+        match this.peeked {
+            Some(x) => EffectResult::Item(x),
+            None => unreachable!(),
+        }
+    }
+
+    /// Get a reference to the next item, without consuming it.
+    ///
+    /// The returned effective produces `None` if there are no more items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::pin;
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let mut e = pin!(wrappers::iterator([1, 2]).peekable());
+    ///
+    /// assert_eq!(e.as_mut().peek().get(), Some(&1));
+    /// assert_eq!(e.as_mut().peek().get(), Some(&1));
+    ///
+    /// let v: Vec<i32> = e.collect().get();
+    /// assert_eq!(v, [1, 2]);
+    /// ```
+    pub fn peek(self: Pin<&mut Self>) -> Peek<'_, E> {
+        Peek {
+            peekable: Some(self),
+        }
+    }
+
+    /// Consume the next item only if it matches the predicate.
+    ///
+    /// The returned effective produces `None` if the next item does not match, or if there are no more items.
+    pub fn next_if<F>(self: Pin<&mut Self>, func: F) -> NextIf<'_, E, F>
+    where
+        F: FnOnce(&E::Item) -> bool,
+    {
+        NextIf {
+            peekable: self,
+            func: Some(func),
+        }
+    }
+
+    /// Consume the next item only if it is equal to `expected`.
+    ///
+    /// The returned effective produces `None` if the next item is not equal, or if there are no more items.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::pin::pin;
+    /// use effective::{impls::EffectiveExt, wrappers};
+    ///
+    /// let mut e = pin!(wrappers::iterator("-12".chars()).peekable());
+    ///
+    /// let negative = e.as_mut().next_if_eq(&'-').get().is_some();
+    /// assert!(negative);
+    /// assert_eq!(e.as_mut().next_if_eq(&'-').get(), None);
+    /// assert_eq!(e.as_mut().next_if(|c| c.is_ascii_digit()).get(), Some('1'));
+    /// ```
+    pub fn next_if_eq<'a, T>(self: Pin<&'a mut Self>, expected: &'a T) -> NextIfEq<'a, E, T>
+    where
+        T: ?Sized,
+        E::Item: PartialEq<T>,
+    {
+        NextIfEq {
+            peekable: self,
+            expected,
+        }
+    }
+
+    fn poll_next_if(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        func: impl FnOnce(&E::Item) -> bool,
+    ) -> EffectResult<Option<E::Item>, E::Failure, Single, E::Async> {
+        let mut this = self;
+        match this.as_mut().poll_peek(cx) {
+            EffectResult::Item(x) => {
+                if !func(x) {
+                    return EffectResult::Item(None);
+                }
+            }
+            EffectResult::Failure(x) => return EffectResult::Failure(x),
+            EffectResult::Done(Multiple) => return EffectResult::Item(None),
+            EffectResult::Pending(x) => return EffectResult::Pending(x),
+        }
+        EffectResult::Item(this.project().peeked.take())
+    }
+}
+
+impl<E> Effective for Peekable<E>
+where
+    E: Effective<Produces = Multiple>,
+{
+    type Item = E::Item;
+    type Failure = E::Failure;
+    type Produces = Multiple;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        if let Some(x) = this.peeked.take() {
+            return EffectResult::Item(x);
+        }
+        let Some(inner) = this.inner.as_mut().as_pin_mut() else {
+            return EffectResult::Done(Multiple);
+        };
+        match inner.poll_effect(cx) {
+            EffectResult::Item(x) => EffectResult::Item(x),
+            EffectResult::Failure(x) => EffectResult::Failure(x),
+            EffectResult::Done(Multiple) => {
+                this.inner.set(None);
+                EffectResult::Done(Multiple)
+            }
+            EffectResult::Pending(x) => EffectResult::Pending(x),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = usize::from(self.peeked.is_some());
+        match &self.inner {
+            Some(inner) => {
+                let (lower, upper) = inner.size_hint();
+                (
+                    lower.saturating_add(peeked),
+                    upper.and_then(|x| x.checked_add(peeked)),
+                )
+            }
+            None => (peeked, Some(peeked)),
+        }
+    }
+}
+
+/// Produced by the [`peek()`](Peekable::peek) method
+pub struct Peek<'a, E>
+where
+    E: Effective,
+{
+    peekable: Option<Pin<&'a mut Peekable<E>>>,
+}
+
+impl<'a, E> Effective for Peek<'a, E>
+where
+    E: Effective<Produces = Multiple>,
+{
+    type Item = Option<&'a E::Item>;
+    type Failure = E::Failure;
+    type Produces = Single;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let this = self.get_mut();
+        let mut peekable = this.peekable.take().expect("polled after completion");
+        match peekable.as_mut().poll_peek(cx) {
+            EffectResult::Item(_) | EffectResult::Done(Multiple) => {}
+            EffectResult::Failure(x) => return EffectResult::Failure(x),
+            EffectResult::Pending(x) => {
+                this.peekable = Some(peekable);
+                return EffectResult::Pending(x);
+            }
+        }
+        EffectResult::Item(peekable.project().peeked.as_ref())
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`next_if()`](Peekable::next_if) method
+    pub struct NextIf<'a, E, F>
+    where
+        E: Effective,
+    {
+        peekable: Pin<&'a mut Peekable<E>>,
+        func: Option<F>,
+    }
+);
+
+impl<E, F> Effective for NextIf<'_, E, F>
+where
+    E: Effective<Produces = Multiple>,
+    F: FnOnce(&E::Item) -> bool,
+{
+    type Item = Option<E::Item>;
+    type Failure = E::Failure;
+    type Produces = Single;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let this = self.project();
+        let func = this.func;
+        this.peekable.as_mut().poll_next_if(cx, |x| {
+            let func = func.take().expect("polled after completion");
+            func(x)
+        })
+    }
+}
+
+/// Produced by the [`next_if_eq()`](Peekable::next_if_eq) method
+pub struct NextIfEq<'a, E, T>
+where
+    E: Effective,
+    T: ?Sized,
+{
+    peekable: Pin<&'a mut Peekable<E>>,
+    expected: &'a T,
+}
+
+impl<E, T> Effective for NextIfEq<'_, E, T>
+where
+    E: Effective<Produces = Multiple>,
+    E::Item: PartialEq<T>,
+    T: ?Sized,
+{
+    type Item = Option<E::Item>;
+    type Failure = E::Failure;
+    type Produces = Single;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let this = self.get_mut();
+        let expected = this.expected;
+        this.peekable.as_mut().poll_next_if(cx, |x| x == expected)
+    }
+}
@@ -0,0 +1,82 @@
+//! Effect adaptors that move the 'fallible' effect in and out of the items
+
+use std::{convert::Infallible, pin::Pin, task::Context};
+
+use crate::{utils::LiftResult, EffectResult, Effective, Failure, SealedMarker};
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`into_result()`](super::EffectiveExt::into_result) method
+    pub struct IntoResult<E> {
+        #[pin]
+        pub(super) inner: Option<E>,
+    }
+);
+
+impl<T, E> Effective for IntoResult<E>
+where
+    E: Effective<Failure = Failure<T>>,
+{
+    type Item = Result<E::Item, T>;
+    type Failure = Infallible;
+    type Produces = E::Produces;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        let Some(inner) = this.inner.as_mut().as_pin_mut() else {
+            // the failure was the last item
+            return EffectResult::Done(SealedMarker::new());
+        };
+        match inner.poll_effect(cx) {
+            EffectResult::Item(x) => EffectResult::Item(Ok(x)),
+            EffectResult::Failure(Failure(x)) => {
+                this.inner.set(None);
+                EffectResult::Item(Err(x))
+            }
+            EffectResult::Done(x) => EffectResult::Done(x),
+            EffectResult::Pending(x) => EffectResult::Pending(x),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Some(inner) => inner.size_hint(),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`from_result()`](super::EffectiveExt::from_result) method
+    pub struct FromResult<E> {
+        #[pin]
+        pub(super) inner: E,
+    }
+);
+
+impl<T, U, E> Effective for FromResult<E>
+where
+    E: Effective<Item = Result<T, U>>,
+    E::Failure: LiftResult<U>,
+{
+    type Item = T;
+    type Failure = <E::Failure as LiftResult<U>>::Failure;
+    type Produces = E::Produces;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        match self.project().inner.poll_effect(cx) {
+            EffectResult::Item(Ok(x)) => EffectResult::Item(x),
+            EffectResult::Item(Err(x)) => {
+                EffectResult::Failure(<E::Failure as LiftResult<U>>::from_err(x))
+            }
+            EffectResult::Failure(x) => EffectResult::Failure(x.into_fail()),
+            EffectResult::Done(x) => EffectResult::Done(x),
+            EffectResult::Pending(x) => EffectResult::Pending(x),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
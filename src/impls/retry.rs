@@ -0,0 +1,357 @@
+//! Effect adaptor that re-creates a fallible effective after a failure
+
+use std::{convert::Infallible, pin::Pin, task::Context, time::Duration};
+
+use crate::{
+    timer::Timer,
+    utils::{from_async, AsyncPair, AsyncWith},
+    wrappers::{self, FromFuture},
+    Blocking, EffectResult, Effective, Failure, Single,
+};
+
+/// Create an effective that runs the effective made by `make`, making a new one
+/// and trying again whenever it fails, for as long as `policy` allows.
+///
+/// The delay between attempts is provided by the [`SleepHook`] of the policy.
+/// If the effectives and the sleeps are blocking, then so is the retry.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use effective::{
+///     impls::{retry::{ExponentialBackoff, RetryPolicy, ThreadSleep}, EffectiveExt},
+///     wrappers,
+/// };
+///
+/// let policy = ExponentialBackoff::new(Duration::from_millis(1), ThreadSleep)
+///     .max_attempts(5)
+///     .retry_if(|e: &&str| *e == "busy");
+///
+/// let mut attempts = 0;
+/// let e = effective::retry(
+///     || {
+///         attempts += 1;
+///         wrappers::fallible(if attempts < 3 { Err("busy") } else { Ok(attempts) })
+///     },
+///     policy,
+/// );
+///
+/// let v: Result<i32, &str> = e.try_get();
+/// assert_eq!(v, Ok(3));
+/// ```
+pub fn retry<F, E, T, P>(make: F, policy: P) -> Retry<F, E, P, P::Sleep>
+where
+    F: FnMut() -> E,
+    E: Effective<Produces = Single, Failure = Failure<T>>,
+    P: RetryPolicy<T>,
+{
+    Retry {
+        make,
+        policy,
+        attempt: 0,
+        state: State::Start,
+    }
+}
+
+/// Decides whether, and when, to retry after a failure
+pub trait RetryPolicy<E> {
+    /// The effective used to wait between attempts
+    type Sleep: Effective<Item = (), Failure = Infallible, Produces = Single>;
+
+    /// Called after attempt number `attempt` (starting at 1) failed with `error`.
+    ///
+    /// Returns the sleep to wait for before trying again, or `None` to stop and produce the failure.
+    fn retry(&mut self, error: &E, attempt: u32) -> Option<Self::Sleep>;
+
+    /// Only retry the failures that `classify` returns true for
+    fn retry_if<C>(self, classify: C) -> RetryIf<Self, C>
+    where
+        Self: Sized,
+        C: FnMut(&E) -> bool,
+    {
+        RetryIf {
+            policy: self,
+            classify,
+        }
+    }
+}
+
+/// Creates the effectives that wait between attempts.
+///
+/// Use [`ThreadSleep`] for blocking effectives, or any [`Timer`] for async effectives.
+///
+/// # Example
+///
+/// A hook that records the delays instead of waiting, for use in tests
+///
+/// ```
+/// use std::{cell::RefCell, time::Duration};
+/// use effective::{
+///     impls::{retry::{ExponentialBackoff, SleepHook}, EffectiveExt},
+///     wrappers,
+/// };
+///
+/// struct MockSleep<'a>(&'a RefCell<Vec<Duration>>);
+///
+/// impl SleepHook for MockSleep<'_> {
+///     type Sleep = wrappers::Once<()>;
+///
+///     fn sleep(&mut self, duration: Duration) -> Self::Sleep {
+///         self.0.borrow_mut().push(duration);
+///         wrappers::once(())
+///     }
+/// }
+///
+/// let delays = RefCell::new(vec![]);
+/// let policy = ExponentialBackoff::new(Duration::from_secs(1), MockSleep(&delays)).max_attempts(4);
+///
+/// let e = effective::retry(|| wrappers::fallible(Err::<(), _>("down")), policy);
+///
+/// let v: Result<(), &str> = e.try_get();
+/// assert_eq!(v, Err("down"));
+/// assert_eq!(
+///     *delays.borrow(),
+///     [Duration::from_secs(1), Duration::from_secs(2), Duration::from_secs(4)],
+/// );
+///
+/// // by default, the delay stops growing at 60 seconds
+/// delays.borrow_mut().clear();
+/// let policy = ExponentialBackoff::new(Duration::from_secs(1), MockSleep(&delays)).max_attempts(100);
+/// let e = effective::retry(|| wrappers::fallible(Err::<(), _>("down")), policy);
+/// let v: Result<(), &str> = e.try_get();
+/// assert_eq!(v, Err("down"));
+/// assert_eq!(delays.borrow().iter().max(), Some(&Duration::from_secs(60)));
+/// ```
+pub trait SleepHook {
+    type Sleep: Effective<Item = (), Failure = Infallible, Produces = Single>;
+
+    /// Create an effective that completes after `duration` has elapsed
+    fn sleep(&mut self, duration: Duration) -> Self::Sleep;
+}
+
+/// A [`SleepHook`] that blocks the current thread
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ThreadSleep;
+
+impl SleepHook for ThreadSleep {
+    type Sleep = BlockingSleep;
+
+    fn sleep(&mut self, duration: Duration) -> Self::Sleep {
+        BlockingSleep(duration)
+    }
+}
+
+/// Any [`Timer`] can be used to sleep asynchronously
+impl<T: Timer> SleepHook for T {
+    type Sleep = FromFuture<T::Sleep>;
+
+    fn sleep(&mut self, duration: Duration) -> Self::Sleep {
+        wrappers::future(Timer::sleep(self, duration))
+    }
+}
+
+/// Produced by [`ThreadSleep`]
+pub struct BlockingSleep(Duration);
+
+impl Effective for BlockingSleep {
+    type Item = ();
+    type Failure = Infallible;
+    type Produces = Single;
+    type Async = Blocking;
+
+    fn poll_effect(self: Pin<&mut Self>, _: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        std::thread::sleep(self.0);
+        EffectResult::Item(())
+    }
+}
+
+/// A [`RetryPolicy`] that doubles the delay after every failed attempt.
+///
+/// By default it makes at most 3 attempts, and waits at most 60 seconds between attempts.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use effective::{impls::{retry::ExponentialBackoff, EffectiveExt}, timer::TokioTimer, wrappers};
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread()
+///     .enable_time()
+///     .build()
+///     .unwrap();
+///
+/// async fn lookup(attempt: u32) -> Result<u32, std::io::Error> {
+///     if attempt < 2 {
+///         Err(std::io::ErrorKind::TimedOut.into())
+///     } else {
+///         Ok(attempt)
+///     }
+/// }
+///
+/// let policy = ExponentialBackoff::new(Duration::from_millis(1), TokioTimer)
+///     .max_delay(Duration::from_millis(10));
+///
+/// let mut attempt = 0;
+/// let e = effective::retry(
+///     || {
+///         attempt += 1;
+///         wrappers::try_future(lookup(attempt))
+///     },
+///     policy,
+/// );
+///
+/// let v: Result<u32, _> = e.block_on(runtime).try_get();
+/// assert_eq!(v.unwrap(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExponentialBackoff<H> {
+    initial: Duration,
+    factor: u32,
+    max_delay: Duration,
+    max_attempts: u32,
+    hook: H,
+}
+
+impl<H: SleepHook> ExponentialBackoff<H> {
+    /// Wait `initial` after the first failure, sleeping with `hook`
+    pub fn new(initial: Duration, hook: H) -> Self {
+        Self {
+            initial,
+            factor: 2,
+            max_delay: Duration::from_secs(60),
+            max_attempts: 3,
+            hook,
+        }
+    }
+
+    /// The most attempts to make, including the first
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// The longest delay to wait between attempts, 60 seconds by default
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// How much the delay grows by after each attempt
+    pub fn factor(mut self, factor: u32) -> Self {
+        self.factor = factor;
+        self
+    }
+}
+
+impl<E, H: SleepHook> RetryPolicy<E> for ExponentialBackoff<H> {
+    type Sleep = H::Sleep;
+
+    fn retry(&mut self, _: &E, attempt: u32) -> Option<Self::Sleep> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        let delay = self
+            .factor
+            .checked_pow(attempt - 1)
+            .and_then(|x| self.initial.checked_mul(x))
+            .unwrap_or(Duration::MAX)
+            .min(self.max_delay);
+        Some(self.hook.sleep(delay))
+    }
+}
+
+/// Produced by the [`retry_if()`](RetryPolicy::retry_if) method
+#[derive(Debug, Clone)]
+pub struct RetryIf<P, C> {
+    policy: P,
+    classify: C,
+}
+
+impl<E, P, C> RetryPolicy<E> for RetryIf<P, C>
+where
+    P: RetryPolicy<E>,
+    C: FnMut(&E) -> bool,
+{
+    type Sleep = P::Sleep;
+
+    fn retry(&mut self, error: &E, attempt: u32) -> Option<Self::Sleep> {
+        if (self.classify)(error) {
+            self.policy.retry(error, attempt)
+        } else {
+            None
+        }
+    }
+}
+
+pin_project_lite::pin_project!(
+    #[project = StateProj]
+    pub(super) enum State<E, S> {
+        Start,
+        Attempt {
+            #[pin]
+            eff: E,
+        },
+        Sleep {
+            #[pin]
+            sleep: S,
+        },
+    }
+);
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`retry()`] function
+    pub struct Retry<F, E, P, S> {
+        make: F,
+        policy: P,
+        attempt: u32,
+        #[pin]
+        state: State<E, S>,
+    }
+);
+
+impl<F, E, T, P, S> Effective for Retry<F, E, P, S>
+where
+    F: FnMut() -> E,
+    E: Effective<Produces = Single, Failure = Failure<T>>,
+    P: RetryPolicy<T, Sleep = S>,
+    S: Effective<Item = (), Failure = Infallible, Produces = Single>,
+    E::Async: AsyncWith<S::Async>,
+{
+    type Item = E::Item;
+    type Failure = E::Failure;
+    type Produces = Single;
+    type Async = AsyncPair<E, S>;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::Start => {
+                    let eff = (this.make)();
+                    this.state.set(State::Attempt { eff });
+                }
+                StateProj::Attempt { eff } => match eff.poll_effect(cx) {
+                    EffectResult::Item(x) => return EffectResult::Item(x),
+                    EffectResult::Failure(x) => {
+                        *this.attempt += 1;
+                        match this.policy.retry(&x.0, *this.attempt) {
+                            Some(sleep) => this.state.set(State::Sleep { sleep }),
+                            None => return EffectResult::Failure(x),
+                        }
+                    }
+                    EffectResult::Done(x) => match x {},
+                    EffectResult::Pending(x) => return EffectResult::Pending(x.into_async()),
+                },
+                StateProj::Sleep { sleep } => match sleep.poll_effect(cx) {
+                    EffectResult::Item(()) => this.state.set(State::Start),
+                    EffectResult::Failure(x) => match x {},
+                    EffectResult::Done(x) => match x {},
+                    EffectResult::Pending(x) => {
+                        return EffectResult::Pending(from_async::<E, S>(x))
+                    }
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! Effect adaptors to subtract the 'iterable' effect by searching the items,
+//! stopping as soon as the answer is known
+
+use std::{ops::ControlFlow, pin::Pin, task::Context};
+
+use crate::{
+    utils::{from_async, from_fail, AsyncPair, AsyncWith, FalliblePair, FallibleWith},
+    EffectResult, Effective, Multiple, Single,
+};
+
+/// Decides the answer of a search, for use with [`Search`] and [`SearchEffect`]
+pub trait Searcher<T> {
+    type Output;
+
+    /// Look at the next item, along with whether it matched the predicate.
+    /// Returns [`ControlFlow::Break`] once the answer is known
+    fn step(&mut self, item: T, matched: bool) -> ControlFlow<Self::Output>;
+
+    /// The answer if all the items were searched
+    fn finish(&mut self) -> Self::Output;
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`find()`](super::EffectiveExt::find), [`find_map()`](super::EffectiveExt::find_map),
+    /// [`position()`](super::EffectiveExt::position), [`any()`](super::EffectiveExt::any),
+    /// [`all()`](super::EffectiveExt::all) and [`nth()`](super::EffectiveExt::nth) methods
+    pub struct Search<E, P, S> {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) predicate: P,
+        pub(super) searcher: S,
+    }
+);
+
+impl<E, P, S> Effective for Search<E, P, S>
+where
+    E: Effective<Produces = Multiple>,
+    P: FnMut(&E::Item) -> bool,
+    S: Searcher<E::Item>,
+{
+    type Item = S::Output;
+    type Failure = E::Failure;
+    type Produces = Single;
+    type Async = E::Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        while let Some(inner) = this.inner.as_mut().as_pin_mut() {
+            match inner.poll_effect(cx) {
+                EffectResult::Item(x) => {
+                    let matched = (this.predicate)(&x);
+                    if let ControlFlow::Break(x) = this.searcher.step(x, matched) {
+                        this.inner.set(None);
+                        return EffectResult::Item(x);
+                    }
+                }
+                EffectResult::Failure(x) => return EffectResult::Failure(x),
+                EffectResult::Done(Multiple) => this.inner.set(None),
+                EffectResult::Pending(x) => return EffectResult::Pending(x),
+            }
+        }
+        EffectResult::Item(this.searcher.finish())
+    }
+}
+
+pin_project_lite::pin_project!(
+    #[project = StateProj]
+    pub(super) enum State<T, C> {
+        Acc,
+        Eff {
+            #[pin]
+            eff: C,
+            item: Option<T>,
+        },
+    }
+);
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`find_effect()`](super::EffectiveExt::find_effect),
+    /// [`position_effect()`](super::EffectiveExt::position_effect),
+    /// [`any_effect()`](super::EffectiveExt::any_effect) and
+    /// [`all_effect()`](super::EffectiveExt::all_effect) methods
+    pub struct SearchEffect<E, P, S, C>
+    where
+        E: Effective,
+    {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) predicate: P,
+        pub(super) searcher: S,
+        #[pin]
+        pub(super) state: State<E::Item, C>,
+    }
+);
+
+impl<E, P, S, C> Effective for SearchEffect<E, P, S, C>
+where
+    E: Effective<Produces = Multiple>,
+    P: FnMut(&E::Item) -> C,
+    S: Searcher<E::Item>,
+    C: Effective<Item = bool, Produces = Single>,
+    E::Async: AsyncWith<C::Async>,
+    E::Failure: FallibleWith<C::Failure>,
+{
+    type Item = S::Output;
+    type Failure = FalliblePair<E, C>;
+    type Produces = Single;
+    type Async = AsyncPair<E, C>;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        loop {
+            match this.state.as_mut().project() {
+                StateProj::Acc => {
+                    let Some(inner) = this.inner.as_mut().as_pin_mut() else {
+                        return EffectResult::Item(this.searcher.finish());
+                    };
+                    match inner.poll_effect(cx) {
+                        EffectResult::Item(x) => {
+                            let eff = (this.predicate)(&x);
+                            this.state.set(State::Eff { eff, item: Some(x) });
+                        }
+                        EffectResult::Failure(x) => return EffectResult::Failure(x.into_fail()),
+                        EffectResult::Done(Multiple) => this.inner.set(None),
+                        EffectResult::Pending(x) => return EffectResult::Pending(x.into_async()),
+                    }
+                }
+                StateProj::Eff { eff, item } => match eff.poll_effect(cx) {
+                    EffectResult::Item(matched) => {
+                        let item = item.take().unwrap();
+                        this.state.set(State::Acc);
+                        if let ControlFlow::Break(x) = this.searcher.step(item, matched) {
+                            this.inner.set(None);
+                            return EffectResult::Item(x);
+                        }
+                    }
+                    EffectResult::Done(x) => match x {},
+                    EffectResult::Failure(x) => return EffectResult::Failure(from_fail::<E, C>(x)),
+                    EffectResult::Pending(x) => {
+                        return EffectResult::Pending(from_async::<E, C>(x))
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Predicate for searches that look at every item
+pub type AlwaysFn<T> = fn(&T) -> bool;
+
+pub type Find<E, P> = Search<E, P, FindSearch>;
+pub type FindMap<E, F> = Search<E, AlwaysFn<<E as Effective>::Item>, FindMapSearch<F>>;
+pub type Position<E, P> = Search<E, P, PositionSearch>;
+pub type Any<E, P> = Search<E, P, AnySearch>;
+pub type All<E, P> = Search<E, P, AllSearch>;
+pub type Nth<E> = Search<E, AlwaysFn<<E as Effective>::Item>, NthSearch>;
+
+pub type FindEffect<E, P, C> = SearchEffect<E, P, FindSearch, C>;
+pub type PositionEffect<E, P, C> = SearchEffect<E, P, PositionSearch, C>;
+pub type AnyEffect<E, P, C> = SearchEffect<E, P, AnySearch, C>;
+pub type AllEffect<E, P, C> = SearchEffect<E, P, AllSearch, C>;
+
+/// Finds the first matching item
+pub struct FindSearch;
+
+impl<T> Searcher<T> for FindSearch {
+    type Output = Option<T>;
+
+    fn step(&mut self, item: T, matched: bool) -> ControlFlow<Option<T>> {
+        if matched {
+            ControlFlow::Break(Some(item))
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+    fn finish(&mut self) -> Option<T> {
+        None
+    }
+}
+
+/// Finds the first item that maps to a value
+pub struct FindMapSearch<F>(pub(super) F);
+
+impl<T, R, F> Searcher<T> for FindMapSearch<F>
+where
+    F: FnMut(T) -> Option<R>,
+{
+    type Output = Option<R>;
+
+    fn step(&mut self, item: T, _: bool) -> ControlFlow<Option<R>> {
+        match (self.0)(item) {
+            Some(x) => ControlFlow::Break(Some(x)),
+            None => ControlFlow::Continue(()),
+        }
+    }
+    fn finish(&mut self) -> Option<R> {
+        None
+    }
+}
+
+/// Finds the index of the first matching item
+pub struct PositionSearch(pub(super) usize);
+
+impl<T> Searcher<T> for PositionSearch {
+    type Output = Option<usize>;
+
+    fn step(&mut self, _: T, matched: bool) -> ControlFlow<Option<usize>> {
+        if matched {
+            ControlFlow::Break(Some(self.0))
+        } else {
+            self.0 += 1;
+            ControlFlow::Continue(())
+        }
+    }
+    fn finish(&mut self) -> Option<usize> {
+        None
+    }
+}
+
+/// Checks whether any item matches
+pub struct AnySearch;
+
+impl<T> Searcher<T> for AnySearch {
+    type Output = bool;
+
+    fn step(&mut self, _: T, matched: bool) -> ControlFlow<bool> {
+        if matched {
+            ControlFlow::Break(true)
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+    fn finish(&mut self) -> bool {
+        false
+    }
+}
+
+/// Checks whether every item matches
+pub struct AllSearch;
+
+impl<T> Searcher<T> for AllSearch {
+    type Output = bool;
+
+    fn step(&mut self, _: T, matched: bool) -> ControlFlow<bool> {
+        if matched {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(false)
+        }
+    }
+    fn finish(&mut self) -> bool {
+        true
+    }
+}
+
+/// Finds the item at the given index
+pub struct NthSearch(pub(super) usize);
+
+impl<T> Searcher<T> for NthSearch {
+    type Output = Option<T>;
+
+    fn step(&mut self, item: T, _: bool) -> ControlFlow<Option<T>> {
+        match self.0.checked_sub(1) {
+            Some(n) => {
+                self.0 = n;
+                ControlFlow::Continue(())
+            }
+            None => ControlFlow::Break(Some(item)),
+        }
+    }
+    fn finish(&mut self) -> Option<T> {
+        None
+    }
+}
@@ -0,0 +1,120 @@
+//! Effect adaptors that bound how long an async effective can wait
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::Context,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    timer::Timer, utils::FallibleWith, Async, EffectResult, Effective, Failure, Iterable,
+    SealedMarker,
+};
+
+/// The failure produced when a [`timeout()`](super::EffectiveExt::timeout)
+/// or [`deadline()`](super::EffectiveExt::deadline) is reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed(());
+
+impl std::fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("deadline has elapsed")
+    }
+}
+
+impl std::error::Error for Elapsed {}
+
+impl From<Elapsed> for std::io::Error {
+    fn from(e: Elapsed) -> Self {
+        std::io::Error::new(std::io::ErrorKind::TimedOut, e)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) enum Limit {
+    Timeout(Duration),
+    Deadline(Instant),
+}
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`timeout()`](super::EffectiveExt::timeout) and
+    /// [`deadline()`](super::EffectiveExt::deadline) methods
+    pub struct Timeout<E, T>
+    where
+        T: Timer,
+    {
+        #[pin]
+        pub(super) inner: Option<E>,
+        pub(super) timer: T,
+        pub(super) limit: Limit,
+        #[pin]
+        pub(super) sleep: Option<T::Sleep>,
+    }
+);
+
+impl<E, T> Effective for Timeout<E, T>
+where
+    E: Effective<Async = Async>,
+    T: Timer,
+    Failure<Elapsed>: FallibleWith<E::Failure>,
+{
+    type Item = E::Item;
+    type Failure = <Failure<Elapsed> as FallibleWith<E::Failure>>::Failure;
+    type Produces = E::Produces;
+    type Async = Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        let Some(inner) = this.inner.as_mut().as_pin_mut() else {
+            if <E::Produces as Iterable>::MULTIPLE {
+                return EffectResult::Done(SealedMarker::new());
+            }
+            panic!("single `Timeout` polled after completion")
+        };
+        // the time starts on the first poll, or the first poll after an item
+        if this.sleep.is_none() {
+            let sleep = match *this.limit {
+                Limit::Timeout(duration) => this.timer.sleep(duration),
+                Limit::Deadline(deadline) => this.timer.sleep_until(deadline),
+            };
+            this.sleep.set(Some(sleep));
+        }
+        match inner.poll_effect(cx) {
+            EffectResult::Item(x) => {
+                if !<E::Produces as Iterable>::MULTIPLE {
+                    this.inner.set(None);
+                    this.sleep.set(None);
+                } else if let Limit::Timeout(_) = this.limit {
+                    this.sleep.set(None);
+                }
+                return EffectResult::Item(x);
+            }
+            EffectResult::Failure(x) => {
+                return EffectResult::Failure(
+                    <Failure<Elapsed> as FallibleWith<E::Failure>>::from_fail(x),
+                )
+            }
+            EffectResult::Done(x) => {
+                this.inner.set(None);
+                this.sleep.set(None);
+                return EffectResult::Done(x);
+            }
+            EffectResult::Pending(Async) => {}
+        }
+        let sleep = this.sleep.as_mut().as_pin_mut().unwrap();
+        if sleep.poll(cx).is_ready() {
+            this.inner.set(None);
+            this.sleep.set(None);
+            return EffectResult::Failure(Failure(Elapsed(())).into_fail());
+        }
+        EffectResult::Pending(Async)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Some(inner) => (0, inner.size_hint().1),
+            None => (0, Some(0)),
+        }
+    }
+}
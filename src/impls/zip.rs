@@ -0,0 +1,131 @@
+//! Effect adaptors that pair up the items of two effectives
+
+use std::{pin::Pin, task::Context};
+
+use crate::{
+    utils::{
+        from_async, from_fail, AsyncPair, AsyncWith, FalliblePair, FallibleWith, IterablePair,
+        IterableWith,
+    },
+    EffectResult, Effective, Iterable, SealedMarker,
+};
+
+pin_project_lite::pin_project!(
+    /// Produced by the [`zip_with()`](super::EffectiveExt::zip_with) method
+    pub struct ZipWith<E1, E2, F>
+    where
+        E1: Effective,
+        E2: Effective,
+    {
+        #[pin]
+        pub(super) first: Option<E1>,
+        #[pin]
+        pub(super) second: Option<E2>,
+        pub(super) left: Option<E1::Item>,
+        pub(super) right: Option<E2::Item>,
+        pub(super) func: F,
+    }
+);
+
+impl<R, E1, E2, F> Effective for ZipWith<E1, E2, F>
+where
+    E1: Effective,
+    E2: Effective,
+    F: FnMut(E1::Item, E2::Item) -> R,
+    E1::Produces: IterableWith<E2::Produces>,
+    E1::Async: AsyncWith<E2::Async>,
+    E1::Failure: FallibleWith<E2::Failure>,
+{
+    type Item = R;
+    type Failure = FalliblePair<E1, E2>;
+    type Produces = IterablePair<E1, E2>;
+    type Async = AsyncPair<E1, E2>;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        let mut this = self.project();
+        let mut pending = None;
+
+        if this.left.is_none() {
+            if let Some(first) = this.first.as_mut().as_pin_mut() {
+                match first.poll_effect(cx) {
+                    EffectResult::Item(x) => {
+                        if !<E1::Produces as Iterable>::MULTIPLE {
+                            this.first.set(None);
+                        }
+                        *this.left = Some(x);
+                    }
+                    EffectResult::Failure(x) => {
+                        // the other side is no longer needed
+                        this.second.set(None);
+                        return EffectResult::Failure(x.into_fail());
+                    }
+                    EffectResult::Done(_) => this.first.set(None),
+                    EffectResult::Pending(x) => pending = Some(x.into_async()),
+                }
+            }
+        }
+
+        // the first side has run out, so the second side must not be asked for another item
+        if this.first.is_none() && this.left.is_none() {
+            this.second.set(None);
+            *this.right = None;
+            return EffectResult::Done(<Self::Produces as SealedMarker>::new());
+        }
+
+        if this.right.is_none() {
+            if let Some(second) = this.second.as_mut().as_pin_mut() {
+                match second.poll_effect(cx) {
+                    EffectResult::Item(x) => {
+                        if !<E2::Produces as Iterable>::MULTIPLE {
+                            this.second.set(None);
+                        }
+                        *this.right = Some(x);
+                    }
+                    EffectResult::Failure(x) => {
+                        this.first.set(None);
+                        return EffectResult::Failure(from_fail::<E1, E2>(x));
+                    }
+                    EffectResult::Done(_) => this.second.set(None),
+                    EffectResult::Pending(x) => pending = Some(from_async::<E1, E2>(x)),
+                }
+            }
+        }
+
+        // the second side has run out, so there will be no more pairs
+        if this.second.is_none() && this.right.is_none() {
+            this.first.set(None);
+            this.second.set(None);
+            *this.left = None;
+            *this.right = None;
+            return EffectResult::Done(<Self::Produces as SealedMarker>::new());
+        }
+
+        if let Some(x) = pending {
+            return EffectResult::Pending(x);
+        }
+
+        match (this.left.take(), this.right.take()) {
+            (Some(left), Some(right)) => EffectResult::Item((this.func)(left, right)),
+            _ => unreachable!("both sides are either ready or done"),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        fn side<E: Effective>(inner: Option<&E>, buffered: bool) -> (usize, Option<usize>) {
+            let (lower, upper) = inner.map_or((0, Some(0)), E::size_hint);
+            let buffered = usize::from(buffered);
+            (
+                lower.saturating_add(buffered),
+                upper.and_then(|x| x.checked_add(buffered)),
+            )
+        }
+
+        let (lower1, upper1) = side(self.first.as_ref(), self.left.is_some());
+        let (lower2, upper2) = side(self.second.as_ref(), self.right.is_some());
+        let upper = match (upper1, upper2) {
+            (Some(x), Some(y)) => Some(x.min(y)),
+            (x, None) | (None, x) => x,
+        };
+        (lower1.min(lower2), upper)
+    }
+}
@@ -5,11 +5,169 @@ use std::{convert::Infallible, ops::ControlFlow, pin::Pin, task::Context};
 
 mod blankets;
 pub use blankets::Shim;
+mod boxed;
+pub use boxed::{BoxEffective, LocalBoxEffective};
+#[cfg(feature = "macros")]
+mod for_effect;
+#[cfg(feature = "macros")]
+mod generator;
 pub mod impls;
+pub mod timer;
 pub mod utils;
 pub mod wrappers;
 
-pub use impls::EffectiveExt;
+pub use impls::{
+    join::{join, join_all},
+    merge::merge_all,
+    retry::retry,
+    EffectiveExt,
+};
+
+/// Write an [`Effective`] using `yield`, `.await` and `?`.
+///
+/// The effects are inferred from the keywords used directly in the body:
+/// * `yield` makes it [`Multiple`], otherwise the value of the body is the [`Single`] item.
+/// * `.await` makes it [`Async`], otherwise it is [`Blocking`].
+/// * `?` makes it fallible with [`Failure<E>`](Failure), otherwise it is [`Infallible`].
+///
+/// Closures, async blocks and nested `effective!` invocations inside the body are not searched,
+/// as they have their own control flow.
+/// The arguments of macro invocations are searched too. If they are not expressions, any
+/// `.await` or `?` in them is assumed to be used, and `yield` is not supported.
+/// The failure type `E` cannot always be inferred from `?` alone, so you might need to name it,
+/// for instance in the return type of the function.
+///
+/// # Example
+///
+/// ```
+/// use effective::{effective, impls::EffectiveExt, Async, Effective, Failure, Multiple};
+///
+/// async fn get_page(page: usize) -> Result<Option<String>, std::num::TryFromIntError> {
+///     /* insert http request */
+/// #   Ok((page < 3).then(|| page.to_string()))
+/// }
+///
+/// fn get_pages() -> impl Effective<
+///     Item = String,
+///     Failure = Failure<std::num::TryFromIntError>,
+///     Produces = Multiple,
+///     Async = Async,
+/// > {
+///     effective! {
+///         let mut page = 0;
+///         while let Some(p) = get_page(page).await? {
+///             yield p;
+///             page += 1;
+///         }
+///     }
+/// }
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+///
+/// // effects inside macro invocations are found too
+/// let len = effective! {
+///     let v = vec![tokio::task::yield_now().await, ()];
+///     v.len()
+/// };
+/// assert_eq!(len.block_on(&runtime).get(), 2);
+///
+/// // nested `effective!` invocations keep their own effects
+/// let handle = runtime.handle().clone();
+/// let sum: i32 = effective! {
+///     let inner = effective! { yield 1; yield 2; };
+///     let single = effective! { tokio::task::yield_now().await; 10 };
+///     inner.sum::<i32>().get() + single.block_on(handle).get()
+/// }
+/// .get();
+/// assert_eq!(sum, 13);
+///
+/// let pages: Result<Vec<String>, _> = get_pages().collect().block_on(runtime).try_get();
+/// assert_eq!(pages.unwrap(), ["0", "1", "2"]);
+///
+/// // the effective can be sent to another thread, as long as everything it holds can be
+/// fn assert_send(_: &impl Send) {}
+/// assert_send(&get_pages());
+///
+/// // with no effects, it's just a single blocking value
+/// let x: i32 = effective! { 1 + 2 }.get();
+/// assert_eq!(x, 3);
+/// ```
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use effective_macros::effective;
+
+/// Loop over the items of an [`Effective`] with [`Produces = Multiple`](Multiple).
+///
+/// Written like a `for` loop, with optional `try` and `await` modifiers:
+/// * `try` propagates any failure out of the surrounding function using `?` semantics.
+///   Without it, the effective must be [`Infallible`].
+/// * `await` waits for the next item, so the loop must be inside an `async` context.
+///   Without it, the effective must be [`Blocking`].
+///
+/// `break`, `continue` and `return` work like they would in a normal `for` loop,
+/// and the loop can be labelled with `for_effect!('outer: for x in e { ... })`.
+///
+/// Inside an [`effective!`] body, the loop body can also `yield`, and the effects
+/// of the loop are part of the effects of the body.
+///
+/// # Example
+///
+/// ```
+/// use effective::{for_effect, impls::EffectiveExt, wrappers};
+///
+/// async fn total_len() -> Result<usize, std::num::ParseIntError> {
+///     let e = wrappers::iterator(["1", "2", "three", "4"])
+///         .map(|x| async move { x.parse::<usize>() })
+///         .flatten_future()
+///         .flatten_fallible();
+///
+///     let mut total = 0;
+///     for_effect!(for try await x in e {
+///         total += x;
+///     });
+///     Ok(total)
+/// }
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// assert!(runtime.block_on(total_len()).is_err());
+///
+/// let mut v = vec![];
+/// for_effect!(for x in wrappers::iterator(1..) {
+///     if x > 4 {
+///         break;
+///     }
+///     v.push(x);
+/// });
+/// assert_eq!(v, [1, 2, 3, 4]);
+///
+/// // transform an effective inside `effective!`, where `for_effect!` can be named by its path
+/// use effective::{Async, Effective, Failure, Multiple};
+/// use std::num::ParseIntError;
+///
+/// fn doubled(
+///     e: impl Effective<Item = i32, Failure = Failure<ParseIntError>, Produces = Multiple, Async = Async>,
+/// ) -> impl Effective<Item = i32, Failure = Failure<ParseIntError>, Produces = Multiple, Async = Async> {
+///     effective::effective! {
+///         effective::for_effect!(for try await x in e {
+///             yield x * 2;
+///         })
+///     }
+/// }
+///
+/// let s = futures_util::stream::iter(["1", "2", "3"].map(str::parse::<i32>));
+/// let v: Result<Vec<i32>, _> = doubled(wrappers::try_stream(s)).collect().block_on(runtime).try_get();
+/// assert_eq!(v.unwrap(), [2, 4, 6]);
+/// ```
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use effective_macros::for_effect;
+
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::for_effect::{next, next_blocking};
+    pub use crate::generator::{multiple, single, Yielder};
+}
 
 mod private {
     use std::convert::Infallible;
@@ -207,19 +365,19 @@ pub trait Effective {
     /// stream state:
     ///
     /// - `EffectResult::Pending(_)` means that this effectives's next value is not ready
-    /// yet. Implementations will ensure that the current task will be notified
-    /// when the next value may be ready.
+    ///   yet. Implementations will ensure that the current task will be notified
+    ///   when the next value may be ready.
     ///
     /// - `EffectResult::Item(val)` means that the effectives has successfully
-    /// produced a value, `val`, and may produce further values on subsequent
-    /// `poll_effect` calls. If this effective has `Produces = Single`, then
-    /// `poll_effect` should not be invoked again.
+    ///   produced a value, `val`, and may produce further values on subsequent
+    ///   `poll_effect` calls. If this effective has `Produces = Single`, then
+    ///   `poll_effect` should not be invoked again.
     ///
     /// - `EffectResult::Done(_)` means that the effective has terminated, and
-    /// `poll_effect` should not be invoked again.
+    ///   `poll_effect` should not be invoked again.
     ///
     /// - `EffectResult::Failure(_)` means that there was a failure processing the next
-    /// item in the effective. `poll_effect` should not be invoked again.
+    ///   item in the effective. `poll_effect` should not be invoked again.
     ///
     /// # Panics
     ///
@@ -231,6 +389,9 @@ pub trait Effective {
     /// Rust's usual rules apply: calls must never cause undefined behavior
     /// (memory corruption, incorrect use of `unsafe` functions, or the like),
     /// regardless of the stream's state.
+    ///
+    /// If this is a problem, [`fuse()`](EffectiveExt::fuse) can be used to ensure that
+    /// `poll_effect` behaves well after completion. See [`FusedEffective`].
     fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> EffectiveResult<Self>;
 
     /// Returns the bounds on the remaining length of the stream.
@@ -268,6 +429,35 @@ pub trait Effective {
     }
 }
 
+/// An [`Effective`] that tracks whether it has finished.
+///
+/// Once [`is_terminated`](FusedEffective::is_terminated) returns `true`, it is safe to call
+/// [`poll_effect`](Effective::poll_effect) again. If `Produces = Multiple`, it will return
+/// `EffectResult::Done(_)`. Otherwise it will stay `EffectResult::Pending(_)` if async.
+/// A single blocking effective has nothing left to return, so it will still panic.
+///
+/// A [`Shim`] of a fused effective is a [`FusedStream`](futures_core::stream::FusedStream) or
+/// [`FusedIterator`](std::iter::FusedIterator), so it keeps returning `None` once it has ended.
+///
+/// # Example
+///
+/// ```
+/// use effective::{impls::EffectiveExt, wrappers, FusedEffective};
+///
+/// let e = wrappers::try_iterator(["1", "two", "3"].map(str::parse::<i32>));
+/// let mut iter = e.shim();
+///
+/// assert_eq!(iter.next(), Some(Ok(1)));
+/// assert!(iter.next().unwrap().is_err());
+/// assert!(iter.inner.is_terminated());
+/// assert_eq!(iter.next(), None);
+/// assert_eq!(iter.next(), None);
+/// ```
+pub trait FusedEffective: Effective {
+    /// Returns `true` if [`poll_effect`](Effective::poll_effect) should no longer be called.
+    fn is_terminated(&self) -> bool;
+}
+
 /// A simpler stable imitation of [`Try`](std::ops::Try)
 pub trait SimpleTry {
     /// The value which will be returned if execution should be stopped
@@ -0,0 +1,235 @@
+//! Timers used by the time based adaptors, such as [`chunks_timeout()`](crate::impls::EffectiveExt::chunks_timeout)
+//! and [`timeout()`](crate::impls::EffectiveExt::timeout)
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+/// A source of sleep futures.
+///
+/// Time based adaptors do not depend on any particular runtime, instead they are
+/// given a timer to create the sleeps they need.
+pub trait Timer {
+    /// The future returned by [`sleep()`](Timer::sleep)
+    type Sleep: Future<Output = ()>;
+
+    /// Create a future that completes after `duration` has elapsed
+    fn sleep(&self, duration: Duration) -> Self::Sleep;
+
+    /// Create a future that completes once `deadline` is reached
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        self.sleep(deadline.saturating_duration_since(Instant::now()))
+    }
+}
+
+impl<T: Timer + ?Sized> Timer for &T {
+    type Sleep = T::Sleep;
+
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        T::sleep(self, duration)
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        T::sleep_until(self, deadline)
+    }
+}
+
+/// A [`Timer`] backed by the tokio time driver.
+///
+/// The sleeps must be polled within a tokio runtime that has time enabled.
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioTimer;
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl Timer for TokioTimer {
+    type Sleep = tokio::time::Sleep;
+
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        tokio::time::sleep(duration)
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        tokio::time::sleep_until(deadline.into())
+    }
+}
+
+/// A [`Timer`] where time only moves forward when [`advance()`](ManualTimer::advance) is called.
+///
+/// Useful for testing time based adaptors without waiting.
+/// Like tokio, deadlines that are too far away to represent are clamped to roughly 30 years from now.
+///
+/// # Example
+///
+/// ```
+/// use std::{future::Future, pin::pin, task::Context, time::Duration};
+/// use effective::timer::{ManualTimer, Timer};
+/// use futures_util::task::noop_waker_ref;
+///
+/// let timer = ManualTimer::new();
+/// let mut sleep = pin!(timer.sleep(Duration::from_secs(10)));
+/// let mut cx = Context::from_waker(noop_waker_ref());
+///
+/// assert!(sleep.as_mut().poll(&mut cx).is_pending());
+/// timer.advance(Duration::from_secs(9));
+/// assert!(sleep.as_mut().poll(&mut cx).is_pending());
+/// timer.advance(Duration::from_secs(1));
+/// assert!(sleep.as_mut().poll(&mut cx).is_ready());
+///
+/// // repeated polls and dropped sleeps do not keep any wakers around
+/// for _ in 0..10 {
+///     let mut sleep = pin!(timer.sleep(Duration::from_secs(10)));
+///     assert!(sleep.as_mut().poll(&mut cx).is_pending());
+///     assert!(sleep.as_mut().poll(&mut cx).is_pending());
+/// }
+/// assert_eq!(timer.waiting(), 0);
+///
+/// // very long sleeps end in the far future instead of overflowing
+/// let mut sleep = pin!(timer.sleep(Duration::MAX));
+/// timer.advance(Duration::from_secs(365 * 24 * 60 * 60));
+/// assert!(sleep.as_mut().poll(&mut cx).is_pending());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ManualTimer {
+    clock: Arc<Mutex<ManualClock>>,
+}
+
+#[derive(Debug)]
+struct ManualClock {
+    now: Instant,
+    next_id: u64,
+    waiting: Vec<Waiting>,
+}
+
+/// A pending sleep. Each sleep has at most one entry, which is removed once it is woken or dropped
+#[derive(Debug)]
+struct Waiting {
+    id: u64,
+    deadline: Instant,
+    waker: Waker,
+}
+
+impl ManualTimer {
+    /// Create a new timer, starting at the current time
+    pub fn new() -> Self {
+        Self {
+            clock: Arc::new(Mutex::new(ManualClock {
+                now: Instant::now(),
+                next_id: 0,
+                waiting: Vec::new(),
+            })),
+        }
+    }
+
+    /// The current time of this timer
+    pub fn now(&self) -> Instant {
+        lock(&self.clock).now
+    }
+
+    /// The number of sleeps that are waiting to be woken
+    pub fn waiting(&self) -> usize {
+        lock(&self.clock).waiting.len()
+    }
+
+    /// Move the time forward, waking any sleeps that have completed
+    pub fn advance(&self, duration: Duration) {
+        let woken = {
+            let mut clock = lock(&self.clock);
+            clock.now = saturating_add(clock.now, duration);
+            let now = clock.now;
+            let (woken, waiting) = std::mem::take(&mut clock.waiting)
+                .into_iter()
+                .partition::<Vec<_>, _>(|w| w.deadline <= now);
+            clock.waiting = waiting;
+            woken
+        };
+        for w in woken {
+            w.waker.wake();
+        }
+    }
+}
+
+impl Default for ManualTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Timer for ManualTimer {
+    type Sleep = ManualSleep;
+
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        self.sleep_until(saturating_add(self.now(), duration))
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        let id = {
+            let mut clock = lock(&self.clock);
+            clock.next_id += 1;
+            clock.next_id
+        };
+        ManualSleep {
+            clock: self.clock.clone(),
+            id,
+            deadline,
+        }
+    }
+}
+
+/// Produced by the [`ManualTimer`]
+#[derive(Debug)]
+pub struct ManualSleep {
+    clock: Arc<Mutex<ManualClock>>,
+    id: u64,
+    deadline: Instant,
+}
+
+impl Future for ManualSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut clock = lock(&self.clock);
+        if clock.now >= self.deadline {
+            return Poll::Ready(());
+        }
+        match clock.waiting.iter_mut().find(|w| w.id == self.id) {
+            Some(w) => {
+                if !w.waker.will_wake(cx.waker()) {
+                    w.waker = cx.waker().clone();
+                }
+            }
+            None => clock.waiting.push(Waiting {
+                id: self.id,
+                deadline: self.deadline,
+                waker: cx.waker().clone(),
+            }),
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for ManualSleep {
+    fn drop(&mut self) {
+        lock(&self.clock).waiting.retain(|w| w.id != self.id);
+    }
+}
+
+/// Roughly 30 years, which is what tokio uses for deadlines that cannot be represented
+const FAR_FUTURE: Duration = Duration::from_secs(86400 * 365 * 30);
+
+fn saturating_add(instant: Instant, duration: Duration) -> Instant {
+    instant
+        .checked_add(duration)
+        .or_else(|| instant.checked_add(FAR_FUTURE))
+        .unwrap_or(instant)
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
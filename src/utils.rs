@@ -142,6 +142,38 @@ impl FallibleWith<Infallible> for Infallible {
     }
 }
 
+/// Represents the fallibility of an effective once a [`Result`] item is lifted into it.
+///
+/// The existing failure type is kept, so the error in the item must convert into it.
+pub trait LiftResult<E>: Fallible {
+    type Failure: Fallible;
+    fn into_fail(self) -> <Self as LiftResult<E>>::Failure;
+    fn from_err(_: E) -> <Self as LiftResult<E>>::Failure;
+}
+
+impl<F, E> LiftResult<E> for Failure<F>
+where
+    F: From<E>,
+{
+    type Failure = Failure<F>;
+    fn into_fail(self) -> Failure<F> {
+        self
+    }
+    fn from_err(x: E) -> Failure<F> {
+        Failure(x.into())
+    }
+}
+
+impl<E> LiftResult<E> for Infallible {
+    type Failure = Failure<E>;
+    fn into_fail(self) -> Failure<E> {
+        match self {}
+    }
+    fn from_err(x: E) -> Failure<E> {
+        Failure(x)
+    }
+}
+
 pub type FalliblePair<E1, E2> =
     <<E1 as Effective>::Failure as FallibleWith<<E2 as Effective>::Failure>>::Failure;
 pub type AsyncPair<E1, E2> =
@@ -0,0 +1,118 @@
+use std::{pin::Pin, task::Context};
+
+use crate::{
+    utils::{
+        from_async, from_fail, AsyncPair, AsyncWith, FalliblePair, FallibleWith, IterablePair,
+        IterableWith,
+    },
+    EffectResult, Effective, EffectiveResult, Iterable, SealedMarker,
+};
+
+pin_project_lite::pin_project!(
+    #[project = StateProj]
+    enum State<L, R> {
+        Left {
+            #[pin]
+            inner: L,
+        },
+        Right {
+            #[pin]
+            inner: R,
+        },
+        // the side that only produces a single item has produced it
+        Done,
+    }
+);
+
+pin_project_lite::pin_project!(
+    /// An [`Effective`] that is one of two different effectives.
+    ///
+    /// The effects of both sides are combined, so a blocking branch and an async branch can be unified
+    /// without boxing. It is only as large as the larger of the two sides.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use effective::{impls::EffectiveExt, wrappers::{self, Either}};
+    ///
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// let e = if false {
+    ///     Either::left(wrappers::iterator([1, 2, 3]))
+    /// } else {
+    ///     Either::right(wrappers::future(async { 4 }))
+    /// };
+    ///
+    /// let v: Vec<i32> = e.collect().block_on(runtime).get();
+    /// assert_eq!(v, [4]);
+    ///
+    /// type Large = wrappers::Once<[u8; 512]>;
+    /// assert!(std::mem::size_of::<Either<Large, Large>>() < 2 * 512);
+    /// ```
+    pub struct Either<L, R> {
+        #[pin]
+        state: State<L, R>,
+    }
+);
+
+impl<L, R> Either<L, R> {
+    /// Create an `Either` from the left effective
+    pub fn left(left: L) -> Self {
+        Either {
+            state: State::Left { inner: left },
+        }
+    }
+
+    /// Create an `Either` from the right effective
+    pub fn right(right: R) -> Self {
+        Either {
+            state: State::Right { inner: right },
+        }
+    }
+}
+
+impl<L, R> Effective for Either<L, R>
+where
+    L: Effective,
+    R: Effective<Item = L::Item>,
+    L::Produces: IterableWith<R::Produces>,
+    L::Async: AsyncWith<R::Async>,
+    L::Failure: FallibleWith<R::Failure>,
+{
+    type Item = L::Item;
+    type Failure = FalliblePair<L, R>;
+    type Produces = IterablePair<L, R>;
+    type Async = AsyncPair<L, R>;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> EffectiveResult<Self> {
+        let mut state = self.project().state;
+        let (x, single) = match state.as_mut().project() {
+            StateProj::Left { inner } => match inner.poll_effect(cx) {
+                EffectResult::Item(x) => (x, !<L::Produces as Iterable>::MULTIPLE),
+                EffectResult::Failure(x) => return EffectResult::Failure(x.into_fail()),
+                EffectResult::Done(_) => return EffectResult::Done(SealedMarker::new()),
+                EffectResult::Pending(x) => return EffectResult::Pending(x.into_async()),
+            },
+            StateProj::Right { inner } => match inner.poll_effect(cx) {
+                EffectResult::Item(x) => (x, !<R::Produces as Iterable>::MULTIPLE),
+                EffectResult::Failure(x) => return EffectResult::Failure(from_fail::<L, R>(x)),
+                EffectResult::Done(_) => return EffectResult::Done(SealedMarker::new()),
+                EffectResult::Pending(x) => return EffectResult::Pending(from_async::<L, R>(x)),
+            },
+            StateProj::Done => return EffectResult::Done(SealedMarker::new()),
+        };
+        // the other side might produce multiple items, so this must report `Done` next
+        if single {
+            state.set(State::Done);
+        }
+        EffectResult::Item(x)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.state {
+            State::Left { inner } => inner.size_hint(),
+            State::Right { inner } => inner.size_hint(),
+            State::Done => (0, Some(0)),
+        }
+    }
+}
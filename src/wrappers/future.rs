@@ -6,7 +6,7 @@ use std::{
     task::{Context, Poll},
 };
 
-use crate::{Async, EffectResult, Effective, Single};
+use crate::{Async, EffectResult, Effective, Failure, Single};
 
 /// Create an [`Effective`] that has no failures, a single value and is async
 pub fn future<F: IntoFuture>(future: F) -> FromFuture<F::IntoFuture> {
@@ -35,3 +35,38 @@ impl<F: Future> Effective for FromFuture<F> {
         }
     }
 }
+
+/// Create an [`Effective`] that has a failure, a single value and is async
+pub fn try_future<F, T, E>(future: F) -> FromTryFuture<F::IntoFuture>
+where
+    F: IntoFuture<Output = Result<T, E>>,
+{
+    FromTryFuture {
+        inner: future.into_future(),
+    }
+}
+
+pin_project_lite::pin_project!(
+    pub struct FromTryFuture<F> {
+        #[pin]
+        pub inner: F,
+    }
+);
+
+impl<F, T, E> Effective for FromTryFuture<F>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    type Item = T;
+    type Failure = Failure<E>;
+    type Produces = Single;
+    type Async = Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        match self.project().inner.poll(cx) {
+            Poll::Ready(Ok(x)) => EffectResult::Item(x),
+            Poll::Ready(Err(x)) => EffectResult::Failure(Failure(x)),
+            Poll::Pending => EffectResult::Pending(Async),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::{convert::Infallible, pin::Pin, task::Context};
 
-use crate::{Blocking, EffectResult, Effective, Multiple};
+use crate::{Blocking, EffectResult, Effective, Failure, Multiple};
 
 /// Create an [`Effective`] that has no failures, multiple values and no async
 pub fn iterator<I: IntoIterator>(iterator: I) -> FromIterator<I::IntoIter> {
@@ -35,3 +35,52 @@ impl<I: Iterator> Effective for FromIterator<I> {
         self.inner.size_hint()
     }
 }
+
+/// Create an [`Effective`] that has a failure, multiple values and no async
+///
+/// # Example
+///
+/// ```
+/// use effective::{impls::EffectiveExt, wrappers};
+///
+/// let e = wrappers::try_iterator(["1", "2", "3"].map(str::parse::<i32>));
+///
+/// let v: Result<Vec<i32>, _> = e.collect().try_get();
+/// assert_eq!(v.unwrap(), [1, 2, 3]);
+/// ```
+pub fn try_iterator<I, T, E>(iterator: I) -> FromTryIterator<I::IntoIter>
+where
+    I: IntoIterator<Item = Result<T, E>>,
+{
+    FromTryIterator {
+        inner: iterator.into_iter(),
+    }
+}
+
+pin_project_lite::pin_project!(
+    pub struct FromTryIterator<I> {
+        pub inner: I,
+    }
+);
+
+impl<I, T, E> Effective for FromTryIterator<I>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+    type Failure = Failure<E>;
+    type Produces = Multiple;
+    type Async = Blocking;
+
+    fn poll_effect(self: Pin<&mut Self>, _: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        match self.project().inner.next() {
+            Some(Ok(x)) => EffectResult::Item(x),
+            Some(Err(x)) => EffectResult::Failure(Failure(x)),
+            None => EffectResult::Done(Multiple),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
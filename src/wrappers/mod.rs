@@ -1,17 +1,21 @@
 //! Where common [`Effective`](crate::Effective) wrapper constructors live
 
+mod either;
 mod fallible;
 mod from_fn;
 mod future;
 mod iterator;
 mod once;
+mod stream;
 mod unfold;
 
 pub use self::{
+    either::Either,
     fallible::{fallible, FromFallible},
     from_fn::{from_fn, from_fn_once, FromFn, FromFnOnce},
-    future::{future, FromFuture},
-    iterator::{iterator, FromIterator},
+    future::{future, try_future, FromFuture, FromTryFuture},
+    iterator::{iterator, try_iterator, FromIterator, FromTryIterator},
     once::{once, Once},
+    stream::{stream, try_stream, FromStream, FromTryStream},
     unfold::{unfold, Unfold},
 };
@@ -0,0 +1,88 @@
+use std::{
+    convert::Infallible,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::{Stream, TryStream};
+
+use crate::{Async, EffectResult, Effective, Failure, Multiple};
+
+/// Create an [`Effective`] that has no failures, multiple values and is async
+pub fn stream<S: Stream>(stream: S) -> FromStream<S> {
+    FromStream { inner: stream }
+}
+
+/// Create an [`Effective`] that has a failure, multiple values and is async
+///
+/// # Example
+///
+/// ```
+/// use effective::{impls::EffectiveExt, wrappers, Effective};
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+///
+/// let s = futures_util::stream::iter(["1", "2", "three"].map(str::parse::<i32>));
+///
+/// let e = wrappers::try_stream(s);
+/// assert_eq!(e.size_hint(), (3, Some(3)));
+///
+/// let v: Result<Vec<i32>, _> = e.collect().block_on(runtime).try_get();
+/// assert!(v.is_err());
+/// ```
+pub fn try_stream<S: TryStream>(stream: S) -> FromTryStream<S> {
+    FromTryStream { inner: stream }
+}
+
+pin_project_lite::pin_project!(
+    pub struct FromStream<S> {
+        #[pin]
+        pub inner: S,
+    }
+);
+
+impl<S: Stream> Effective for FromStream<S> {
+    type Item = S::Item;
+    type Failure = Infallible;
+    type Produces = Multiple;
+    type Async = Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        match self.project().inner.poll_next(cx) {
+            Poll::Ready(Some(x)) => EffectResult::Item(x),
+            Poll::Ready(None) => EffectResult::Done(Multiple),
+            Poll::Pending => EffectResult::Pending(Async),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+pin_project_lite::pin_project!(
+    pub struct FromTryStream<S> {
+        #[pin]
+        pub inner: S,
+    }
+);
+
+impl<S: TryStream> Effective for FromTryStream<S> {
+    type Item = S::Ok;
+    type Failure = Failure<S::Error>;
+    type Produces = Multiple;
+    type Async = Async;
+
+    fn poll_effect(self: Pin<&mut Self>, cx: &mut Context<'_>) -> crate::EffectiveResult<Self> {
+        match self.project().inner.try_poll_next(cx) {
+            Poll::Ready(Some(Ok(x))) => EffectResult::Item(x),
+            Poll::Ready(Some(Err(x))) => EffectResult::Failure(Failure(x)),
+            Poll::Ready(None) => EffectResult::Done(Multiple),
+            Poll::Pending => EffectResult::Pending(Async),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}